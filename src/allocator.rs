@@ -0,0 +1,117 @@
+use core::ptr::NonNull;
+
+#[cfg(not(feature = "allocator-api2"))]
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::boxed::Box;
+
+#[cfg(not(feature = "allocator-api2"))]
+use alloc::boxed::Box;
+
+#[cfg(feature = "allocator-api2")]
+pub use allocator_api2::alloc::{Allocator, Global};
+
+#[cfg(feature = "allocator-api2")]
+pub(crate) type AllocVec<T, A> = allocator_api2::vec::Vec<T, A>;
+
+#[cfg(not(feature = "allocator-api2"))]
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+pub trait Allocator: sealed::Sealed {}
+
+#[cfg(not(feature = "allocator-api2"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Global;
+
+#[cfg(not(feature = "allocator-api2"))]
+impl sealed::Sealed for Global {}
+
+#[cfg(not(feature = "allocator-api2"))]
+impl Allocator for Global {}
+
+#[cfg(not(feature = "allocator-api2"))]
+pub(crate) struct AllocVec<T, A> {
+    inner: alloc::vec::Vec<T>,
+    _alloc: PhantomData<A>,
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+impl<T> AllocVec<T, Global> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            inner: alloc::vec::Vec::new(),
+            _alloc: PhantomData,
+        }
+    }
+
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: alloc::vec::Vec::with_capacity(capacity),
+            _alloc: PhantomData,
+        }
+    }
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+impl<T, A> Deref for AllocVec<T, A> {
+    type Target = alloc::vec::Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+impl<T, A> DerefMut for AllocVec<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(feature = "allocator-api2")]
+pub(crate) fn allocate<T, A: Allocator>(alloc: &A, value: T) -> NonNull<T> {
+    NonNull::from(Box::leak(Box::new_in(value, alloc)))
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+pub(crate) fn allocate<T, A: Allocator>(_alloc: &A, value: T) -> NonNull<T> {
+    NonNull::from(Box::leak(Box::new(value)))
+}
+
+#[cfg(feature = "allocator-api2")]
+pub(crate) unsafe fn deallocate<T, A: Allocator>(alloc: &A, ptr: NonNull<T>) -> T {
+    Box::into_inner(unsafe { Box::from_raw_in(ptr.as_ptr(), alloc) })
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+pub(crate) unsafe fn deallocate<T, A: Allocator>(_alloc: &A, ptr: NonNull<T>) -> T {
+    *unsafe { Box::from_raw(ptr.as_ptr()) }
+}
+
+#[cfg(all(test, feature = "allocator-api2"))]
+pub(crate) struct Counting<'a>(pub &'a core::cell::Cell<usize>);
+
+#[cfg(all(test, feature = "allocator-api2"))]
+unsafe impl Allocator for Counting<'_> {
+    fn allocate(
+        &self,
+        layout: core::alloc::Layout,
+    ) -> Result<NonNull<[u8]>, allocator_api2::alloc::AllocError> {
+        self.0.set(self.0.get() + 1);
+
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: core::alloc::Layout) {
+        self.0.set(self.0.get() - 1);
+
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
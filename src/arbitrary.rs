@@ -0,0 +1,33 @@
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::SharedList;
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for SharedList<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter::<T>()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter::<T>()?.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary() {
+        let bytes = [1, 10, 1, 20, 1, 30, 0];
+
+        let list = SharedList::<u8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+        assert_eq!(list, SharedList::from(vec![10, 20, 30]));
+        assert_eq!(list.validate(), Ok(()));
+
+        let rest = SharedList::<u8>::arbitrary_take_rest(Unstructured::new(&[1, 2, 3])).unwrap();
+
+        assert!(rest.len() <= 3);
+        assert_eq!(rest.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,544 @@
+use core::{fmt, marker::PhantomData, mem};
+
+use crate::allocator::{AllocVec, Allocator, Global};
+
+struct Slot<T> {
+    left: Option<usize>,
+    right: Option<usize>,
+    value: Option<T>,
+}
+
+pub struct ArenaList<T, A: Allocator = Global> {
+    slots: AllocVec<Slot<T>, A>,
+    free: Option<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T> ArenaList<T> {
+    pub const fn new() -> Self {
+        Self {
+            slots: AllocVec::new(),
+            free: None,
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: AllocVec::with_capacity(capacity),
+            free: None,
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+}
+
+impl<T, A: Allocator> ArenaList<T, A> {
+    #[cfg(feature = "allocator-api2")]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            slots: AllocVec::new_in(alloc),
+            free: None,
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            slots: AllocVec::with_capacity_in(capacity, alloc),
+            free: None,
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let vacant = self.slots.len() - self.len;
+
+        self.slots.reserve(additional.saturating_sub(vacant));
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.and_then(|index| self.slots[index].value.as_ref())
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.and_then(|index| self.slots[index].value.as_ref())
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.and_then(|index| self.slots[index].value.as_mut())
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.and_then(|index| self.slots[index].value.as_mut())
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let index = self.occupy(Slot {
+            left: None,
+            right: self.head,
+            value: value.into(),
+        });
+
+        match self.head {
+            Some(head) => self.slots[head].left = index.into(),
+            None => self.tail = index.into(),
+        }
+
+        self.head = index.into();
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let index = self.occupy(Slot {
+            left: self.tail,
+            right: None,
+            value: value.into(),
+        });
+
+        match self.tail {
+            Some(tail) => self.slots[tail].right = index.into(),
+            None => self.head = index.into(),
+        }
+
+        self.tail = index.into();
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        let slot = self.vacate(head);
+
+        self.head = slot.right;
+
+        match self.head {
+            Some(right) => self.slots[right].left = None,
+            None => self.tail = None,
+        }
+
+        slot.value
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let slot = self.vacate(tail);
+
+        self.tail = slot.left;
+
+        match self.tail {
+            Some(left) => self.slots[left].right = None,
+            None => self.head = None,
+        }
+
+        slot.value
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free = None;
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> ArenaIter<'_, T> {
+        ArenaIter {
+            slots: &self.slots,
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> ArenaIterMut<'_, T> {
+        ArenaIterMut {
+            slots: self.slots.as_mut_ptr(),
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    fn occupy(&mut self, slot: Slot<T>) -> usize {
+        self.len += 1;
+
+        match self.free {
+            Some(index) => {
+                self.free = self.slots[index].right;
+                self.slots[index] = slot;
+
+                index
+            }
+            None => {
+                self.slots.push(slot);
+
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    fn vacate(&mut self, index: usize) -> Slot<T> {
+        let vacant = Slot {
+            left: None,
+            right: self.free,
+            value: None,
+        };
+
+        self.free = index.into();
+        self.len -= 1;
+
+        mem::replace(&mut self.slots[index], vacant)
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for ArenaList<T> {
+    fn clone(&self) -> Self {
+        let mut list = Self::with_capacity(self.len);
+
+        list.extend(self.iter().cloned());
+
+        list
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for ArenaList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for ArenaList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for ArenaList<T, A> {}
+
+impl<T> FromIterator<T> for ArenaList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for ArenaList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+pub struct ArenaIter<'a, T> {
+    slots: &'a [Slot<T>],
+    front: Option<usize>,
+    back: Option<usize>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.and_then(|index| {
+            let slot = &self.slots[index];
+
+            self.front = slot.right;
+            self.len -= 1;
+
+            slot.value.as_ref()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ArenaIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.and_then(|index| {
+            let slot = &self.slots[index];
+
+            self.back = slot.left;
+            self.len -= 1;
+
+            slot.value.as_ref()
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIter<'_, T> {}
+
+pub struct ArenaIterMut<'a, T> {
+    slots: *mut Slot<T>,
+    front: Option<usize>,
+    back: Option<usize>,
+    len: usize,
+    _list: PhantomData<&'a mut Slot<T>>,
+}
+
+impl<'a, T> Iterator for ArenaIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.and_then(|index| {
+            let slot = unsafe { &mut *self.slots.add(index) };
+
+            self.front = slot.right;
+            self.len -= 1;
+
+            slot.value.as_mut()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ArenaIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.and_then(|index| {
+            let slot = unsafe { &mut *self.slots.add(index) };
+
+            self.back = slot.left;
+            self.len -= 1;
+
+            slot.value.as_mut()
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for ArenaIterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for ArenaIterMut<'_, T> {}
+
+pub struct ArenaIntoIter<T, A: Allocator = Global> {
+    list: ArenaList<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for ArenaIntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for ArenaIntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for ArenaIntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for ArenaList<T, A> {
+    type Item = T;
+    type IntoIter = ArenaIntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArenaIntoIter { list: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a ArenaList<T, A> {
+    type Item = &'a T;
+    type IntoIter = ArenaIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut ArenaList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = ArenaIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_new() {
+        static EMPTY: ArenaList<i32> = ArenaList::new();
+
+        #[derive(Default)]
+        struct Queue {
+            items: ArenaList<i32>,
+        }
+
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.capacity(), 0);
+        assert!(Queue::default().items.is_empty());
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = ArenaList::with_capacity(3);
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn reuse() {
+        let mut list = ArenaList::with_capacity(4);
+
+        for round in 0..100 {
+            list.push_back(round);
+            list.push_back(round + 1);
+            list.push_front(round - 1);
+
+            assert_eq!(list.pop_front(), Some(round - 1));
+            assert_eq!(list.pop_back(), Some(round + 1));
+        }
+
+        assert_eq!(list.len(), 100);
+        assert_eq!(list.slots.len(), 102);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..100).collect::<Vec<_>>()
+        );
+
+        let capacity = list.capacity();
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert_eq!(list.capacity(), capacity);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = (1..=4).collect::<ArenaList<_>>();
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().len(), 4);
+
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        *list.front_mut().unwrap() += 1;
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&11));
+        assert_eq!(iter.next_back(), Some(&40));
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.next_back(), Some(&30));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.clone(), list);
+        assert_eq!(format!("{list:?}"), "[11, 20, 30, 40]");
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![11, 20, 30, 40]);
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn new_in() {
+        use core::cell::Cell;
+
+        use crate::allocator::Counting;
+
+        let live = Cell::new(0);
+
+        {
+            let mut list = ArenaList::with_capacity_in(4, Counting(&live));
+
+            list.extend([1, 2, 3]);
+            list.push_front(0);
+
+            assert_eq!(live.get(), 1);
+            assert_eq!(list.pop_back(), Some(3));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        }
+
+        assert_eq!(live.get(), 0);
+
+        let mut list = ArenaList::new_in(Counting(&live));
+
+        list.push_back(1);
+
+        assert_eq!(live.get(), 1);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(live.get(), 0);
+    }
+}
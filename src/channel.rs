@@ -0,0 +1,240 @@
+use core::{fmt, sync::atomic::Ordering::SeqCst};
+
+use alloc::sync::Arc;
+
+use crate::{
+    sync::{blocking, AtomicUsize},
+    Closed, SharedList, TryRecvError,
+};
+
+blocking! {
+    use std::time::Duration;
+}
+
+struct Handles {
+    senders: AtomicUsize,
+    receivers: AtomicUsize,
+}
+
+pub struct Sender<T> {
+    list: SharedList<T>,
+    handles: Arc<Handles>,
+}
+
+pub struct Receiver<T> {
+    list: SharedList<T>,
+    handles: Arc<Handles>,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    split(SharedList::new())
+}
+
+pub fn bounded<T>(bound: usize) -> (Sender<T>, Receiver<T>) {
+    split(SharedList::with_capacity_bound(bound))
+}
+
+fn split<T>(list: SharedList<T>) -> (Sender<T>, Receiver<T>) {
+    let handles = Arc::new(Handles {
+        senders: AtomicUsize::new(1),
+        receivers: AtomicUsize::new(1),
+    });
+
+    let sender = Sender {
+        list: list.clone(),
+        handles: Arc::clone(&handles),
+    };
+
+    (sender, Receiver { list, handles })
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), Closed<T>> {
+        self.list.push_back(value).map(drop)
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        self.list.is_closed()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+impl<T> Receiver<T> {
+    blocking! {
+        pub fn recv(&self) -> Option<Arc<T>> {
+            self.list.pop_front_blocking()
+        }
+
+        pub fn recv_timeout(&self, timeout: Duration) -> Option<Arc<T>> {
+            self.list.pop_front_timeout(timeout)
+        }
+    }
+
+    pub fn try_recv(&self) -> Result<Arc<T>, TryRecvError> {
+        let closed = self.list.is_closed();
+
+        match self.list.pop_front() {
+            Some(value) => Ok(value),
+            None if closed => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    blocking! {
+        pub fn iter(&self) -> impl Iterator<Item = Arc<T>> + '_ {
+            core::iter::from_fn(|| self.recv())
+        }
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        self.list.is_closed()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.handles.senders.fetch_add(1, SeqCst);
+
+        Self {
+            list: self.list.clone(),
+            handles: Arc::clone(&self.handles),
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.handles.receivers.fetch_add(1, SeqCst);
+
+        Self {
+            list: self.list.clone(),
+            handles: Arc::clone(&self.handles),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if self.handles.senders.fetch_sub(1, SeqCst) == 1 {
+            self.list.close();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if self.handles.receivers.fetch_sub(1, SeqCst) == 1 {
+            self.list.close();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sender { .. }")
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Receiver { .. }")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::blocking;
+
+    blocking! {
+        use std::thread;
+    }
+
+    blocking! {
+        #[test]
+        fn send_recv() {
+            let (sender, receiver) = channel();
+
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+            sender.send(1).unwrap();
+            sender.clone().send(2).unwrap();
+
+            assert_eq!(receiver.len(), 2);
+            assert_eq!(receiver.recv().as_deref(), Some(&1));
+
+            drop(sender);
+
+            assert!(receiver.is_disconnected());
+            assert_eq!(receiver.try_recv().as_deref(), Ok(&2));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn receivers_dropped() {
+        let (sender, receiver) = channel();
+
+        drop(receiver.clone());
+
+        assert!(!sender.is_disconnected());
+
+        drop(receiver);
+
+        assert_eq!(sender.send(1).unwrap_err().into_inner(), 1);
+    }
+
+    blocking! {
+        #[test]
+        fn threads() {
+            let (sender, receiver) = bounded(4);
+
+            let producers = (0..4)
+                .map(|offset| {
+                    let sender = sender.clone();
+
+                    thread::spawn(move || {
+                        for value in 0..100 {
+                            sender.send(value * 4 + offset).unwrap();
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            drop(sender);
+
+            let consumer = thread::spawn({
+                let receiver = receiver.clone();
+
+                move || receiver.iter().map(|a| *a).collect::<Vec<_>>()
+            });
+
+            let mut received = receiver.iter().map(|a| *a).collect::<Vec<_>>();
+
+            for producer in producers {
+                producer.join().unwrap();
+            }
+
+            received.extend(consumer.join().unwrap());
+            received.sort();
+
+            assert_eq!(received, (0..400).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use core::{fmt, ops::Deref};
+
+use alloc::sync::Arc;
+
+use crate::{Node, SharedList};
+
+pub struct CircularList<T> {
+    list: SharedList<T>,
+}
+
+impl<T> CircularList<T> {
+    pub fn new() -> Self {
+        Self {
+            list: SharedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> SharedList<T> {
+        self.list
+    }
+
+    pub fn right(&self, node: &Node<T>) -> Option<Node<T>> {
+        node.right().or_else(|| self.list.head())
+    }
+
+    pub fn left(&self, node: &Node<T>) -> Option<Node<T>> {
+        node.left().or_else(|| self.list.tail())
+    }
+
+    pub fn rotate(&self) -> Option<Arc<T>> {
+        loop {
+            let head = self.list.head()?;
+
+            if self.list.move_to_back(&head) {
+                break head.value().into();
+            }
+        }
+    }
+
+    pub fn cycle(&self) -> Cycle<'_, T> {
+        Cycle {
+            list: self,
+            next: self.list.head(),
+            stop: None,
+        }
+    }
+
+    pub fn cycle_from(&self, node: &Node<T>) -> Cycle<'_, T> {
+        Cycle {
+            list: self,
+            next: node.clone().into(),
+            stop: None,
+        }
+    }
+
+    pub fn around(&self, node: &Node<T>) -> Cycle<'_, T> {
+        Cycle {
+            list: self,
+            next: node.clone().into(),
+            stop: node.clone().into(),
+        }
+    }
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for CircularList<T> {
+    type Target = SharedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T> From<SharedList<T>> for CircularList<T> {
+    fn from(list: SharedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+pub struct Cycle<'a, T> {
+    list: &'a CircularList<T>,
+    next: Option<Node<T>>,
+    stop: Option<Node<T>>,
+}
+
+impl<T> Cycle<'_, T> {
+    pub fn stop_at(mut self, node: &Node<T>) -> Self {
+        self.stop = node.clone().into();
+
+        self
+    }
+}
+
+impl<T> Iterator for Cycle<'_, T> {
+    type Item = Arc<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take()?;
+
+        self.next = self
+            .list
+            .right(&node)
+            .filter(|next| Some(next) != self.stop.as_ref());
+
+        Some(node.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraparound() {
+        let list = CircularList::from(SharedList::from(vec![1, 2, 3]));
+
+        let head = list.head().unwrap();
+        let tail = list.tail().unwrap();
+
+        assert_eq!(list.right(&tail), Some(head.clone()));
+        assert_eq!(list.left(&head), Some(tail.clone()));
+        assert_eq!(
+            list.cycle().take(7).map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3, 1]
+        );
+        assert_eq!(
+            list.around(&tail).map(|a| *a).collect::<Vec<_>>(),
+            vec![3, 1, 2]
+        );
+        assert_eq!(
+            list.cycle_from(&tail)
+                .stop_at(&tail)
+                .map(|a| *a)
+                .collect::<Vec<_>>(),
+            vec![3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn rotate() {
+        let list = CircularList::from(SharedList::from(vec![1, 2, 3]));
+
+        assert_eq!(list.rotate().as_deref(), Some(&1));
+        assert_eq!(list.rotate().as_deref(), Some(&2));
+        assert_eq!(list.front().as_deref(), Some(&3));
+        assert_eq!(list.len(), 3);
+
+        list.clear();
+
+        assert_eq!(list.rotate(), None);
+        assert_eq!(list.cycle().next(), None);
+    }
+}
@@ -0,0 +1,336 @@
+use alloc::sync::Arc;
+
+use crate::{InsertError, Node, SharedList};
+
+pub struct Cursor<'a, T> {
+    list: &'a SharedList<T>,
+    node: Option<Node<T>>,
+    index: Option<usize>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub(crate) fn new(
+        list: &'a SharedList<T>,
+        node: Option<Node<T>>,
+        index: Option<usize>,
+    ) -> Self {
+        Self { list, node, index }
+    }
+
+    pub fn current(&self) -> Option<Arc<T>> {
+        self.node.as_ref().map(|node| node.value())
+    }
+
+    pub fn peek_left(&self) -> Option<Arc<T>> {
+        match self.node.as_ref() {
+            Some(node) => node.left(),
+            None => self.list.tail(),
+        }
+        .map(|node| node.value())
+    }
+
+    pub fn peek_right(&self) -> Option<Arc<T>> {
+        match self.node.as_ref() {
+            Some(node) => node.right(),
+            None => self.list.head(),
+        }
+        .map(|node| node.value())
+    }
+
+    pub fn index(&mut self) -> Option<usize> {
+        let node = self.node.as_ref()?;
+
+        if self.index.is_none() {
+            let mut index = 0;
+            let mut left = node.left();
+
+            while let Some(node) = left {
+                left = node.left();
+                index += 1;
+            }
+
+            self.index = index.into();
+        }
+
+        self.index
+    }
+
+    pub fn move_left(&mut self) {
+        if let Some(node) = self.node.take() {
+            self.node = node.left();
+            self.index = self.index.and_then(|index| index.checked_sub(1));
+        } else {
+            self.node = self.list.tail();
+            self.index = None;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if let Some(node) = self.node.take() {
+            self.node = node.right();
+            self.index = self.index.map(|index| index + 1);
+        } else {
+            self.node = self.list.head();
+            self.index = Some(0);
+        }
+    }
+}
+
+pub struct CursorMut<'a, T> {
+    cursor: Cursor<'a, T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub(crate) fn new(cursor: Cursor<'a, T>) -> Self {
+        Self { cursor }
+    }
+
+    pub fn as_cursor(&self) -> &Cursor<'a, T> {
+        &self.cursor
+    }
+
+    pub fn current(&self) -> Option<Arc<T>> {
+        self.cursor.current()
+    }
+
+    pub fn peek_left(&self) -> Option<Arc<T>> {
+        self.cursor.peek_left()
+    }
+
+    pub fn peek_right(&self) -> Option<Arc<T>> {
+        self.cursor.peek_right()
+    }
+
+    pub fn index(&mut self) -> Option<usize> {
+        self.cursor.index()
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor.move_left()
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor.move_right()
+    }
+
+    pub fn insert_before(&mut self, value: T) -> Result<Node<T>, InsertError<T>> {
+        if let Some(node) = self.cursor.node.as_ref() {
+            let mid = self.cursor.list.insert_before(node, value)?;
+
+            self.cursor.index = self.cursor.index.map(|index| index + 1);
+
+            Ok(mid)
+        } else {
+            Ok(self.cursor.list.push_back(value)?)
+        }
+    }
+
+    pub fn insert_after(&mut self, value: T) -> Result<Node<T>, InsertError<T>> {
+        if let Some(node) = self.cursor.node.as_ref() {
+            self.cursor.list.insert_after(node, value)
+        } else {
+            Ok(self.cursor.list.push_front(value)?)
+        }
+    }
+
+    pub fn remove_current(&mut self) -> Option<Arc<T>> {
+        let node = self.cursor.node.take()?;
+
+        self.cursor.node = node.right();
+
+        if self.cursor.node.is_none() {
+            self.cursor.index = None;
+        }
+
+        self.cursor.list.remove(&node)
+    }
+
+    pub fn splice_before(&mut self, other: &SharedList<T>) -> bool {
+        if self.cursor.list.ptr_eq(other) {
+            return false;
+        }
+
+        let Some(chain) = other.take_chain() else {
+            return true;
+        };
+
+        let Some(node) = self.cursor.node.as_ref() else {
+            self.cursor.list.splice_back(chain);
+
+            return true;
+        };
+
+        match self.cursor.list.splice_before(node, chain) {
+            Ok(()) => {
+                self.cursor.index = None;
+
+                true
+            }
+            Err(chain) => {
+                other.splice_back(chain);
+
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SharedList;
+
+    #[test]
+    fn cursor_front() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.current().map(|a| *a), Some(1));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.peek_left(), None);
+        assert_eq!(cursor.peek_right().map(|a| *a), Some(2));
+
+        cursor.move_right();
+
+        assert_eq!(cursor.current().map(|a| *a), Some(2));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_right();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+
+        cursor.move_right();
+
+        assert_eq!(cursor.current().map(|a| *a), Some(1));
+    }
+
+    #[test]
+    fn cursor_back() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+        list.push_back(3).unwrap();
+
+        let mut cursor = list.cursor_back();
+
+        assert_eq!(cursor.current().map(|a| *a), Some(3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_left();
+        cursor.move_left();
+
+        assert_eq!(cursor.current().map(|a| *a), Some(1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_left();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_left().map(|a| *a), Some(3));
+        assert_eq!(cursor.peek_right().map(|a| *a), Some(1));
+    }
+
+    #[test]
+    fn cursor_mut_insert() {
+        let list = SharedList::new();
+
+        list.push_back(2).unwrap();
+
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(1).unwrap();
+        cursor.insert_after(3).unwrap();
+
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_right();
+        cursor.move_right();
+
+        cursor.insert_before(4).unwrap();
+        cursor.insert_after(0).unwrap();
+
+        assert_eq!(*list.head().unwrap().value(), 0);
+        assert_eq!(*list.tail().unwrap().value(), 4);
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn cursor_mut_remove_current() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+        list.push_back(3).unwrap();
+
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_right();
+
+        assert_eq!(cursor.remove_current().map(|a| *a), Some(2));
+        assert_eq!(cursor.current().map(|a| *a), Some(3));
+        assert_eq!(cursor.index(), Some(1));
+
+        assert_eq!(cursor.remove_current().map(|a| *a), Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+
+        assert_eq!(*list.tail().unwrap().value(), 1);
+        assert_eq!(list.iter().map(|a| *a).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn cursor_mut_splice_before() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(4).unwrap();
+
+        let other = SharedList::new();
+
+        other.push_back(2).unwrap();
+        other.push_back(3).unwrap();
+
+        let mut cursor = list.cursor_back_mut();
+
+        cursor.splice_before(&other);
+
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(other.head(), None);
+        assert_eq!(other.tail(), None);
+
+        other.push_back(5).unwrap();
+        cursor.move_right();
+        cursor.splice_before(&other);
+
+        other.push_back(0).unwrap();
+        cursor.move_right();
+        cursor.splice_before(&other);
+
+        assert_eq!(*list.head().unwrap().value(), 0);
+        assert_eq!(*list.tail().unwrap().value(), 5);
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1, 0]
+        );
+
+        let mut cursor = list.cursor_front_mut();
+
+        list.pop_front();
+        other.push_back(6).unwrap();
+
+        assert!(!cursor.splice_before(&other));
+        assert_eq!(other, SharedList::from(vec![6]));
+        assert_eq!(list.len(), 5);
+    }
+}
@@ -0,0 +1,89 @@
+use core::fmt::{self, Write};
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use crate::{Node, SharedList};
+
+fn id<T>(node: &Node<T>) -> String {
+    format!("n{:p}", Arc::as_ptr(&node.routes))
+}
+
+impl<T: fmt::Debug> SharedList<T> {
+    pub fn to_dot(&self) -> String {
+        let head = self.head();
+        let tail = self.tail();
+
+        let mut nodes = Vec::new();
+
+        for (start, step) in [
+            (head.clone(), Node::right as fn(&Node<T>) -> Option<Node<T>>),
+            (tail.clone(), Node::left),
+        ] {
+            let mut next = start;
+
+            while let Some(node) = next.take_if(|node| !nodes.contains(node)) {
+                next = step(&node);
+                nodes.push(node);
+            }
+        }
+
+        let mut dot = String::from("digraph {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for (name, end) in [("head", &head), ("tail", &tail)] {
+            let _ = writeln!(dot, "    {name} [shape=plaintext];");
+
+            if let Some(end) = end {
+                let _ = writeln!(dot, "    {name} -> {};", id(end));
+            }
+        }
+
+        for node in &nodes {
+            let label = format!("{:?}", node.value()).escape_debug().to_string();
+
+            let _ = writeln!(dot, "    {} [label=\"{label}\"];", id(node));
+
+            for (name, link) in [("left", node.left()), ("right", node.right())] {
+                if let Some(link) = link {
+                    let _ = writeln!(dot, "    {} -> {} [label={name}];", id(node), id(&link));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_dot() {
+        let list = SharedList::from(vec!["a", "b"]);
+
+        let head = id(&list.head().unwrap());
+        let tail = id(&list.tail().unwrap());
+
+        let dot = list.to_dot();
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains(&format!("head -> {head};")));
+        assert!(dot.contains(&format!("tail -> {tail};")));
+        assert!(dot.contains(&format!("{head} [label=\"\\\"a\\\"\"];")));
+        assert!(dot.contains(&format!("{head} -> {tail} [label=right];")));
+        assert!(dot.contains(&format!("{tail} -> {head} [label=left];")));
+        assert_eq!(dot.matches("[label=").count(), 4);
+
+        let empty = SharedList::<i32>::new().to_dot();
+
+        assert!(!empty.contains("->"));
+    }
+}
@@ -0,0 +1,187 @@
+use core::{error::Error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WouldBlock;
+
+impl fmt::Display for WouldBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation would block")
+    }
+}
+
+impl Error for WouldBlock {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Closed<T>(pub T);
+
+impl<T> Closed<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for Closed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Closed(..)")
+    }
+}
+
+impl<T> fmt::Display for Closed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("list is closed")
+    }
+}
+
+impl<T> Error for Closed<T> {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TryPushError<T> {
+    Full(T),
+    Closed(T),
+    WouldBlock(T),
+}
+
+impl<T> TryPushError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::Closed(value) | Self::WouldBlock(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TryPushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("Full(..)"),
+            Self::Closed(_) => f.write_str("Closed(..)"),
+            Self::WouldBlock(_) => f.write_str("WouldBlock(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TryPushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("list is at its capacity bound"),
+            Self::Closed(_) => fmt::Display::fmt(&Closed(()), f),
+            Self::WouldBlock(_) => fmt::Display::fmt(&WouldBlock, f),
+        }
+    }
+}
+
+impl<T> Error for TryPushError<T> {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InsertError<T> {
+    Closed(T),
+    Detached(T),
+}
+
+impl<T> InsertError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Closed(value) | Self::Detached(value) => value,
+        }
+    }
+}
+
+impl<T> From<Closed<T>> for InsertError<T> {
+    fn from(Closed(value): Closed<T>) -> Self {
+        Self::Closed(value)
+    }
+}
+
+impl<T> fmt::Debug for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed(_) => f.write_str("Closed(..)"),
+            Self::Detached(_) => f.write_str("Detached(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed(_) => fmt::Display::fmt(&Closed(()), f),
+            Self::Detached(_) => f.write_str("node is not linked into this list"),
+        }
+    }
+}
+
+impl<T> Error for InsertError<T> {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct OutOfOrder<T>(pub T);
+
+impl<T> OutOfOrder<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for OutOfOrder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OutOfOrder(..)")
+    }
+}
+
+impl<T> fmt::Display for OutOfOrder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value would break the list's ordering")
+    }
+}
+
+impl<T> Error for OutOfOrder<T> {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    Empty,
+    Disconnected,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("channel is empty"),
+            Self::Disconnected => f.write_str("channel is disconnected"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    DanglingEnd,
+    HeadHasLeft,
+    Asymmetric { index: usize },
+    TailMismatch { index: usize },
+    LengthMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingEnd => f.write_str("only one of head and tail is set"),
+            Self::HeadHasLeft => f.write_str("head has a left neighbour"),
+            Self::Asymmetric { index } => {
+                write!(
+                    f,
+                    "node {index} and its right neighbour disagree on their link"
+                )
+            }
+            Self::TailMismatch { index } => {
+                write!(
+                    f,
+                    "walk from head ended at node {index}, which is not the tail"
+                )
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "list reports {expected} nodes but {found} were walked")
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
@@ -0,0 +1,30 @@
+use alloc::{sync::Arc, vec::Vec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Front,
+    Back,
+    Middle,
+}
+
+pub(crate) type Hook<T> = Arc<dyn Fn(&T, Position) + Send + Sync>;
+
+pub(crate) struct Hooks<T> {
+    pub insert: Vec<Hook<T>>,
+    pub remove: Vec<Hook<T>>,
+}
+
+impl<T> Default for Hooks<T> {
+    fn default() -> Self {
+        Self {
+            insert: Vec::new(),
+            remove: Vec::new(),
+        }
+    }
+}
+
+pub(crate) fn fire<T>(hooks: Vec<Hook<T>>, value: &T, position: Position) {
+    for hook in hooks {
+        hook(value, position);
+    }
+}
@@ -0,0 +1,426 @@
+use core::fmt;
+
+use alloc::{boxed::Box, vec::Vec};
+
+type Link<T> = Option<Box<Entry<T>>>;
+
+struct Entry<T> {
+    value: T,
+    priority: u32,
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Entry<T> {
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |entry| entry.size)
+}
+
+fn merge<T>(left: Link<T>, right: Link<T>) -> Link<T> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), right.into());
+                left.update();
+
+                left.into()
+            } else {
+                right.left = merge(left.into(), right.left.take());
+                right.update();
+
+                right.into()
+            }
+        }
+    }
+}
+
+fn split<T>(link: Link<T>, index: usize) -> (Link<T>, Link<T>) {
+    let Some(mut entry) = link else {
+        return (None, None);
+    };
+
+    let left_size = size(&entry.left);
+
+    if index <= left_size {
+        let (left, right) = split(entry.left.take(), index);
+
+        entry.left = right;
+        entry.update();
+
+        (left, entry.into())
+    } else {
+        let (left, right) = split(entry.right.take(), index - left_size - 1);
+
+        entry.right = left;
+        entry.update();
+
+        (entry.into(), right)
+    }
+}
+
+/// An owned sequence stored as an implicit treap.
+///
+/// `get`, `insert`, `remove` and `split_off` take O(log n). The type is separate from
+/// `SharedList`: it has no node handles, no sharing and no locking.
+pub struct IndexedSeq<T> {
+    root: Link<T>,
+    seed: u32,
+}
+
+impl<T> IndexedSeq<T> {
+    pub const fn new() -> Self {
+        Self {
+            root: None,
+            seed: 0x9e37_79b9,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn get(&self, mut index: usize) -> Option<&T> {
+        let mut next = self.root.as_deref();
+
+        while let Some(entry) = next {
+            let left_size = size(&entry.left);
+
+            if index < left_size {
+                next = entry.left.as_deref();
+            } else if index == left_size {
+                return Some(&entry.value);
+            } else {
+                index -= left_size + 1;
+                next = entry.right.as_deref();
+            }
+        }
+
+        None
+    }
+
+    pub fn get_mut(&mut self, mut index: usize) -> Option<&mut T> {
+        let mut next = self.root.as_deref_mut();
+
+        while let Some(entry) = next {
+            let left_size = size(&entry.left);
+
+            if index < left_size {
+                next = entry.left.as_deref_mut();
+            } else if index == left_size {
+                return Some(&mut entry.value);
+            } else {
+                index -= left_size + 1;
+                next = entry.right.as_deref_mut();
+            }
+        }
+
+        None
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let entry = Box::new(Entry {
+            value,
+            priority: self.priority(),
+            size: 1,
+            left: None,
+            right: None,
+        });
+
+        let (left, right) = split(self.root.take(), index);
+
+        self.root = merge(merge(left, entry.into()), right);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let (left, right) = split(self.root.take(), index);
+        let (entry, right) = split(right, 1);
+
+        self.root = merge(left, right);
+
+        entry.map(|entry| entry.value)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.insert(0, value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.insert(self.len(), value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.len().checked_sub(1)?)
+    }
+
+    pub fn split_off(&mut self, index: usize) -> IndexedSeq<T> {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let (left, right) = split(self.root.take(), index);
+
+        self.root = left;
+
+        IndexedSeq {
+            root: right,
+            seed: self.priority(),
+        }
+    }
+
+    pub fn split_at(mut self, index: usize) -> (IndexedSeq<T>, IndexedSeq<T>) {
+        let right = self.split_off(index);
+
+        (self, right)
+    }
+
+    pub fn append(&mut self, other: &mut IndexedSeq<T>) {
+        self.root = merge(self.root.take(), other.root.take());
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    pub fn iter(&self) -> IndexedIter<'_, T> {
+        let mut iter = IndexedIter {
+            stack: Vec::new(),
+            len: self.len(),
+        };
+
+        iter.descend(self.root.as_deref());
+
+        iter
+    }
+
+    fn priority(&mut self) -> u32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+
+        self.seed
+    }
+}
+
+impl<T> Default for IndexedSeq<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for IndexedSeq<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IndexedSeq<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for IndexedSeq<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for IndexedSeq<T> {}
+
+impl<T> FromIterator<T> for IndexedSeq<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T> Extend<T> for IndexedSeq<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+pub struct IndexedIter<'a, T> {
+    stack: Vec<&'a Entry<T>>,
+    len: usize,
+}
+
+impl<'a, T> IndexedIter<'a, T> {
+    fn descend(&mut self, mut next: Option<&'a Entry<T>>) {
+        while let Some(entry) = next {
+            self.stack.push(entry);
+            next = entry.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.stack.pop()?;
+
+        self.descend(entry.right.as_deref());
+        self.len -= 1;
+
+        Some(&entry.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
+
+pub struct IndexedIntoIter<T> {
+    list: IndexedSeq<T>,
+}
+
+impl<T> Iterator for IndexedIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IndexedIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIntoIter<T> {}
+
+impl<T> IntoIterator for IndexedSeq<T> {
+    type Item = T;
+    type IntoIter = IndexedIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IndexedIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IndexedSeq<T> {
+    type Item = &'a T;
+    type IntoIter = IndexedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn depth<T>(link: &Link<T>) -> usize {
+        link.as_ref()
+            .map_or(0, |entry| 1 + depth(&entry.left).max(depth(&entry.right)))
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut list = IndexedSeq::new();
+        let mut expected = Vec::new();
+
+        for value in 0..1000 {
+            let index = value * 7 % (expected.len() + 1);
+
+            list.insert(index, value);
+            expected.insert(index, value);
+        }
+
+        for step in 0..300 {
+            let index = step * 13 % expected.len();
+
+            assert_eq!(list.remove(index), Some(expected.remove(index)));
+        }
+
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.remove(expected.len()), None);
+        assert!(depth(&list.root) < 64);
+
+        for (index, value) in expected.iter().enumerate() {
+            assert_eq!(list.get(index), Some(value));
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = IndexedSeq::new();
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        *list.get_mut(1).unwrap() *= 10;
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn split_at() {
+        let list = (0..10).collect::<IndexedSeq<_>>();
+
+        let (mut left, mut right) = list.split_at(4);
+
+        assert_eq!(left.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(right.len(), 6);
+        assert_eq!(right.get(0), Some(&4));
+
+        right.append(&mut left);
+
+        assert!(left.is_empty());
+        assert_eq!(
+            right.into_iter().collect::<Vec<_>>(),
+            vec![4, 5, 6, 7, 8, 9, 0, 1, 2, 3]
+        );
+    }
+}
@@ -0,0 +1,530 @@
+use core::{fmt, marker::PhantomData, mem, ptr::NonNull};
+
+use alloc::boxed::Box;
+
+use crate::allocator::{self, Allocator, Global};
+
+struct InlineNode<T> {
+    left: Option<NonNull<InlineNode<T>>>,
+    right: Option<NonNull<InlineNode<T>>>,
+    value: T,
+}
+
+pub struct InlineList<T, A: Allocator = Global> {
+    head: Option<NonNull<InlineNode<T>>>,
+    tail: Option<NonNull<InlineNode<T>>>,
+    len: usize,
+    alloc: A,
+    _nodes: PhantomData<Box<InlineNode<T>>>,
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for InlineList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for InlineList<T, A> {}
+
+impl<T> InlineList<T> {
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc: Global,
+            _nodes: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> InlineList<T, A> {
+    #[cfg(feature = "allocator-api2")]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            _nodes: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = allocator::allocate(
+            &self.alloc,
+            InlineNode {
+                left: None,
+                right: self.head,
+                value,
+            },
+        );
+
+        match self.head {
+            Some(head) => unsafe { (*head.as_ptr()).left = node.into() },
+            None => self.tail = node.into(),
+        }
+
+        self.head = node.into();
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let node = allocator::allocate(
+            &self.alloc,
+            InlineNode {
+                left: self.tail,
+                right: None,
+                value,
+            },
+        );
+
+        match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).right = node.into() },
+            None => self.head = node.into(),
+        }
+
+        self.tail = node.into();
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            let head = unsafe { allocator::deallocate(&self.alloc, head) };
+
+            self.head = head.right;
+
+            match self.head {
+                Some(right) => unsafe { (*right.as_ptr()).left = None },
+                None => self.tail = None,
+            }
+
+            self.len -= 1;
+
+            head.value
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| {
+            let tail = unsafe { allocator::deallocate(&self.alloc, tail) };
+
+            self.tail = tail.left;
+
+            match self.tail {
+                Some(left) => unsafe { (*left.as_ptr()).right = None },
+                None => self.head = None,
+            }
+
+            self.len -= 1;
+
+            tail.value
+        })
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+
+        match self.tail {
+            Some(tail) => unsafe {
+                (*tail.as_ptr()).right = other_head.into();
+                (*other_head.as_ptr()).left = tail.into();
+            },
+            None => self.head = other_head.into(),
+        }
+
+        self.tail = other.tail.take();
+        self.len += mem::take(&mut other.len);
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn iter(&self) -> InlineIter<'_, T> {
+        InlineIter {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> InlineIterMut<'_, T> {
+        InlineIterMut {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _list: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for InlineList<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for InlineList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for InlineList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for InlineList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for InlineList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for InlineList<T, A> {}
+
+impl<T> FromIterator<T> for InlineList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for InlineList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+pub struct InlineIter<'a, T> {
+    front: Option<NonNull<InlineNode<T>>>,
+    back: Option<NonNull<InlineNode<T>>>,
+    len: usize,
+    _list: PhantomData<&'a InlineNode<T>>,
+}
+
+unsafe impl<T: Sync> Send for InlineIter<'_, T> {}
+unsafe impl<T: Sync> Sync for InlineIter<'_, T> {}
+
+impl<'a, T> Iterator for InlineIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.map(|node| {
+            let node = unsafe { &*node.as_ptr() };
+
+            self.front = node.right;
+            self.len -= 1;
+
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for InlineIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.map(|node| {
+            let node = unsafe { &*node.as_ptr() };
+
+            self.back = node.left;
+            self.len -= 1;
+
+            &node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for InlineIter<'_, T> {}
+
+pub struct InlineIterMut<'a, T> {
+    front: Option<NonNull<InlineNode<T>>>,
+    back: Option<NonNull<InlineNode<T>>>,
+    len: usize,
+    _list: PhantomData<&'a mut InlineNode<T>>,
+}
+
+unsafe impl<T: Send> Send for InlineIterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for InlineIterMut<'_, T> {}
+
+impl<'a, T> Iterator for InlineIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.map(|node| {
+            let node = unsafe { &mut *node.as_ptr() };
+
+            self.front = node.right;
+            self.len -= 1;
+
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for InlineIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.map(|node| {
+            let node = unsafe { &mut *node.as_ptr() };
+
+            self.back = node.left;
+            self.len -= 1;
+
+            &mut node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for InlineIterMut<'_, T> {}
+
+pub struct InlineIntoIter<T, A: Allocator = Global> {
+    list: InlineList<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for InlineIntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for InlineIntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for InlineIntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for InlineList<T, A> {
+    type Item = T;
+    type IntoIter = InlineIntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InlineIntoIter { list: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a InlineList<T, A> {
+    type Item = &'a T;
+    type IntoIter = InlineIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut InlineList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = InlineIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_new() {
+        static EMPTY: InlineList<i32> = InlineList::new();
+
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.iter().next(), None);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = InlineList::new();
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = (1..=4).collect::<InlineList<_>>();
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().len(), 4);
+
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        *list.front_mut().unwrap() += 1;
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&11));
+        assert_eq!(iter.next_back(), Some(&40));
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.next_back(), Some(&30));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![11, 20, 30, 40]);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = (1..=2).collect::<InlineList<_>>();
+        let mut other = (3..=4).collect::<InlineList<_>>();
+
+        list.append(&mut other);
+        list.append(&mut InlineList::new());
+
+        assert!(other.is_empty());
+        assert_eq!(other.iter().next(), None);
+        assert_eq!(list.len(), 4);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+
+        other.append(&mut list);
+        other.push_back(5);
+
+        assert!(list.is_empty());
+        assert_eq!(other.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn iter_send_sync() {
+        fn send_sync<I: Send + Sync>(_: I) {}
+
+        let mut list = (1..=3).collect::<InlineList<_>>();
+
+        send_sync(list.iter());
+        send_sync(list.iter_mut());
+
+        std::thread::scope(|scope| {
+            let iter = list.iter_mut();
+
+            scope.spawn(move || iter.for_each(|value| *value *= 2));
+        });
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn drop() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let list = (0..100)
+            .map(|_| Rc::clone(&value))
+            .collect::<InlineList<_>>();
+
+        assert_eq!(Rc::strong_count(&value), 101);
+
+        std::mem::drop(list);
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn new_in() {
+        use core::cell::Cell;
+
+        use crate::allocator::Counting;
+
+        let live = Cell::new(0);
+
+        {
+            let mut list = InlineList::new_in(Counting(&live));
+
+            list.push_back(1);
+            list.push_front(0);
+
+            assert_eq!(live.get(), 2);
+            assert_eq!(list.pop_back(), Some(1));
+            assert_eq!(live.get(), 1);
+
+            list.extend([2, 3]);
+
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3]);
+        }
+
+        assert_eq!(live.get(), 0);
+    }
+}
@@ -0,0 +1,247 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+
+use alloc::sync::Arc;
+
+use std::collections::{hash_map::RandomState, HashMap};
+
+use crate::{sync::Mutex, Iter, Node, SharedList};
+
+type Index<K, T, S> = HashMap<K, Node<(K, T)>, S>;
+
+pub struct KeyedList<K, T, S = RandomState> {
+    list: SharedList<(K, T)>,
+    index: Mutex<Index<K, T, S>>,
+}
+
+impl<K: Eq + Hash + Clone, T> KeyedList<K, T> {
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K: Eq + Hash + Clone, T, S: BuildHasher> KeyedList<K, T, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            list: SharedList::new(),
+            index: Mutex::new(HashMap::with_hasher(hasher)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, (K, T)> {
+        self.list.iter()
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.lock().unwrap().contains_key(key)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<Arc<(K, T)>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.get_node(key).map(|node| node.value())
+    }
+
+    pub fn get_node<Q>(&self, key: &Q) -> Option<Node<(K, T)>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn push_front(&self, key: K, value: T) -> Option<Arc<(K, T)>> {
+        let mut index = self.index.lock().unwrap();
+
+        let old = index.remove(&key).and_then(|node| self.list.remove(&node));
+
+        if let Ok(node) = self.list.push_front((key.clone(), value)) {
+            index.insert(key, node);
+        }
+
+        old
+    }
+
+    pub fn push_back(&self, key: K, value: T) -> Option<Arc<(K, T)>> {
+        let mut index = self.index.lock().unwrap();
+
+        let old = index.remove(&key).and_then(|node| self.list.remove(&node));
+
+        if let Ok(node) = self.list.push_back((key.clone(), value)) {
+            index.insert(key, node);
+        }
+
+        old
+    }
+
+    pub fn remove<Q>(&self, key: &Q) -> Option<Arc<(K, T)>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let node = self.index.lock().unwrap().remove(key)?;
+
+        self.list.remove(&node)
+    }
+
+    pub fn move_to_front<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let index = self.index.lock().unwrap();
+
+        index
+            .get(key)
+            .is_some_and(|node| self.list.move_to_front(node))
+    }
+
+    pub fn move_to_back<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let index = self.index.lock().unwrap();
+
+        index
+            .get(key)
+            .is_some_and(|node| self.list.move_to_back(node))
+    }
+
+    pub fn pop_front(&self) -> Option<Arc<(K, T)>> {
+        let mut index = self.index.lock().unwrap();
+
+        let entry = self.list.pop_front()?;
+
+        index.remove(&entry.0);
+
+        entry.into()
+    }
+
+    pub fn pop_back(&self) -> Option<Arc<(K, T)>> {
+        let mut index = self.index.lock().unwrap();
+
+        let entry = self.list.pop_back()?;
+
+        index.remove(&entry.0);
+
+        entry.into()
+    }
+
+    pub fn clear(&self) {
+        let mut index = self.index.lock().unwrap();
+
+        index.clear();
+
+        self.list.clear();
+    }
+}
+
+impl<K: Eq + Hash + Clone, T> Default for KeyedList<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, T: fmt::Debug, S> fmt::Debug for KeyedList<K, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+
+        for entry in self.list.iter() {
+            map.entry(&entry.0, &entry.1);
+        }
+
+        map.finish()
+    }
+}
+
+impl<K: Eq + Hash + Clone, T> FromIterator<(K, T)> for KeyedList<K, T> {
+    fn from_iter<I: IntoIterator<Item = (K, T)>>(iter: I) -> Self {
+        let list = Self::new();
+
+        for (key, value) in iter {
+            list.push_back(key, value);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys<'a>(list: &KeyedList<&'a str, i32>) -> Vec<&'a str> {
+        list.iter().map(|entry| entry.0).collect()
+    }
+
+    #[test]
+    fn get_remove() {
+        let list = [("a", 1), ("b", 2), ("c", 3)]
+            .into_iter()
+            .collect::<KeyedList<_, _>>();
+
+        assert_eq!(list.get("b").map(|entry| entry.1), Some(2));
+        assert_eq!(list.get("d"), None);
+        assert_eq!(list.remove("b").map(|entry| entry.1), Some(2));
+        assert_eq!(list.remove("b"), None);
+        assert!(!list.contains_key("b"));
+        assert_eq!(keys(&list), vec!["a", "c"]);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn replace() {
+        let list = KeyedList::new();
+
+        assert_eq!(list.push_back("a", 1), None);
+        assert_eq!(list.push_back("b", 2), None);
+        assert_eq!(list.push_back("a", 3).map(|entry| entry.1), Some(1));
+
+        assert_eq!(keys(&list), vec!["b", "a"]);
+        assert_eq!(list.get("a").map(|entry| entry.1), Some(3));
+        assert_eq!(format!("{list:?}"), r#"{"b": 2, "a": 3}"#);
+    }
+
+    #[test]
+    fn lru() {
+        let list = KeyedList::new();
+
+        for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+            list.push_front(key, value);
+        }
+
+        assert!(list.move_to_front("a"));
+        assert!(!list.move_to_front("z"));
+        assert_eq!(keys(&list), vec!["a", "c", "b"]);
+
+        assert_eq!(list.pop_back().map(|entry| entry.0), Some("b"));
+        assert!(!list.contains_key("b"));
+
+        assert!(list.move_to_back("a"));
+        assert_eq!(list.pop_front().map(|entry| entry.0), Some("c"));
+        assert_eq!(keys(&list), vec!["a"]);
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert!(!list.contains_key("a"));
+    }
+}
@@ -1,18 +1,138 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "spin")))]
+compile_error!("either the `std` or the `spin` feature must be enabled");
+
+mod sync;
+#[doc(hidden)]
+pub use sync::TryLockError as __TryLockError;
+
 mod node;
-use node::Node;
+pub use node::{Node, NodeIterator, RevNodeIterator, WeakNode};
+
+mod cursor;
+pub use cursor::{Cursor, CursorMut};
+
+mod error;
+pub use error::{
+    Closed, InsertError, OutOfOrder, TryPushError, TryRecvError, ValidationError, WouldBlock,
+};
+
+mod allocator;
+pub use allocator::{Allocator, Global};
+
+mod inline;
+pub use inline::{InlineIntoIter, InlineIter, InlineIterMut, InlineList};
+
+mod arena;
+pub use arena::{ArenaIntoIter, ArenaIter, ArenaIterMut, ArenaList};
+
+mod unrolled;
+pub use unrolled::{UnrolledIntoIter, UnrolledIter, UnrolledIterMut, UnrolledList};
+
+mod indexed;
+pub use indexed::{IndexedIntoIter, IndexedIter, IndexedSeq};
+
+mod persistent;
+pub use persistent::{PersistentIter, PersistentList};
+
+mod local;
+pub use local::{LocalIter, LocalList, LocalNode};
+
+mod hooks;
+pub use hooks::Position;
 
 mod list;
-pub use list::LinkedList;
+pub use list::{
+    ChunkBy, Drain, Iter, ListHandle, Nodes, SharedList, Snapshot, Transaction, Windows,
+};
+
+mod owned;
+pub use owned::LinkedList;
+
+mod circular;
+pub use circular::{CircularList, Cycle};
+
+mod sorted;
+pub use sorted::{SortedList, SortedNode};
+
+mod priority;
+pub use priority::PriorityQueue;
+
+mod work_stealing;
+pub use work_stealing::WorkStealingDeque;
+
+mod channel;
+pub use channel::{bounded, channel, Receiver, Sender};
+
+#[cfg(feature = "std")]
+mod keyed;
+#[cfg(feature = "std")]
+pub use keyed::KeyedList;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "debug")]
+mod dot;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use metrics::{stats, Stats};
+
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::IntoStream;
+
+#[cfg(feature = "futures")]
+mod sink;
+#[cfg(feature = "futures")]
+pub use sink::IntoSink;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rayon")]
+pub use rayon::ParIter;
+
+#[cfg(feature = "lock-free")]
+mod lock_free;
+#[cfg(feature = "lock-free")]
+pub use lock_free::LockFreeQueue;
 
 #[macro_export]
 macro_rules! try_lock {
     ($mutex:expr) => {
+        $crate::try_lock!($mutex, continue)
+    };
+    ($mutex:expr, $would_block:expr) => {
         match $mutex.try_lock() {
             Ok(lock) => lock,
             Err(err) => match err {
-                std::sync::TryLockError::WouldBlock => continue,
+                $crate::__TryLockError::WouldBlock => $would_block,
                 _ => panic!("{err}"),
             },
         }
     };
 }
+
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::LinkedList::new()
+    };
+    ($value:expr; $n:expr) => {
+        ::core::iter::repeat($value)
+            .take($n)
+            .collect::<$crate::LinkedList<_>>()
+    };
+    ($($value:expr),+ $(,)?) => {
+        <$crate::LinkedList<_> as ::core::iter::FromIterator<_>>::from_iter([$($value),+])
+    };
+}
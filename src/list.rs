@@ -1,165 +1,4400 @@
-use std::sync::{Arc, Mutex};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    sync::atomic::Ordering::{Relaxed, SeqCst},
+    task::Waker,
+};
 
-use crate::{try_lock, Node};
+use alloc::{
+    collections::VecDeque,
+    string::String,
+    sync::Arc,
+    vec::{self, Vec},
+};
 
-pub struct LinkedList<T> {
-    head: Arc<Mutex<Option<Node<T>>>>,
-    tail: Arc<Mutex<Option<Node<T>>>>,
+blocking! {
+    use std::time::{Duration, Instant};
 }
 
-impl<T> LinkedList<T> {
+#[cfg(feature = "futures")]
+use core::task::{Context, Poll};
+
+#[cfg(feature = "metrics")]
+use crate::metrics::{self, Counter};
+
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    hooks::{fire, Hooks},
+    node::Routes,
+    sync::{
+        blocking, spinning, AtomicBool, AtomicUsize, Backoff, Condvar, Mutex, MutexGuard, NodeLock,
+    },
+    try_lock, ArenaList, Closed, Cursor, CursorMut, InsertError, LinkedList, Node, Position,
+    TryPushError, ValidationError, WouldBlock,
+};
+
+const POOL_LIMIT: usize = 64;
+
+#[derive(Default)]
+struct Waiting {
+    available: Vec<Waker>,
+    vacated: Vec<Waker>,
+}
+
+pub(crate) struct Inner<T> {
+    head: Mutex<Option<Node<T>>>,
+    tail: Mutex<Option<Node<T>>>,
+    len: AtomicUsize,
+    bound: usize,
+    claimed: AtomicUsize,
+    closed: AtomicBool,
+    waiting: Mutex<Waiting>,
+    waiters: AtomicUsize,
+    available: Condvar,
+    blocked: AtomicUsize,
+    vacated: Condvar,
+    free: Mutex<Vec<Arc<NodeLock<Routes<T>>>>>,
+    pool: usize,
+    hooks: Mutex<Hooks<T>>,
+    hooked: AtomicBool,
+}
+
+impl<T> Inner<T> {
+    fn new(head: Option<Node<T>>, tail: Option<Node<T>>, len: usize, bound: usize) -> Self {
+        Self {
+            head: Mutex::new(head),
+            tail: Mutex::new(tail),
+            len: AtomicUsize::new(len),
+            bound,
+            claimed: AtomicUsize::new(len),
+            closed: AtomicBool::new(false),
+            waiting: Mutex::new(Waiting::default()),
+            waiters: AtomicUsize::new(0),
+            available: Condvar::new(),
+            blocked: AtomicUsize::new(0),
+            vacated: Condvar::new(),
+            free: Mutex::new(Vec::new()),
+            pool: POOL_LIMIT,
+            hooks: Mutex::new(Hooks::default()),
+            hooked: AtomicBool::new(false),
+        }
+    }
+
+    fn try_claim(&self) -> bool {
+        self.claimed
+            .fetch_update(SeqCst, SeqCst, |claimed| {
+                (claimed < self.bound).then_some(claimed + 1)
+            })
+            .is_ok()
+    }
+
+    blocking! {
+        fn claim(&self) -> bool {
+            loop {
+                if self.closed.load(SeqCst) {
+                    break false;
+                }
+
+                if self.try_claim() {
+                    break true;
+                }
+
+                let mut waiting = self.waiting.lock().unwrap();
+
+                self.blocked.fetch_add(1, SeqCst);
+
+                while self.claimed.load(SeqCst) >= self.bound && !self.closed.load(SeqCst) {
+                    waiting = self.vacated.wait(waiting).unwrap();
+                }
+
+                self.blocked.fetch_sub(1, SeqCst);
+            }
+        }
+    }
+
+    spinning! {
+        fn claim(&self) -> bool {
+            let backoff = Backoff::new();
+
+            loop {
+                if self.closed.load(SeqCst) {
+                    break false;
+                }
+
+                if self.try_claim() {
+                    break true;
+                }
+
+                backoff.snooze();
+            }
+        }
+    }
+
+    fn release(&self, len: usize) {
+        self.claimed.fetch_sub(len, SeqCst);
+
+        if self.blocked.load(SeqCst) > 0 {
+            let mut waiting = self.waiting.lock().unwrap();
+
+            if len == 1 {
+                self.vacated.notify_one();
+            } else {
+                self.vacated.notify_all();
+            }
+
+            let wakers = mem::take(&mut waiting.vacated);
+
+            drop(waiting);
+
+            wake(&self.blocked, wakers);
+        }
+    }
+
+    fn shrink(&self, len: usize) {
+        self.len.fetch_sub(len, Relaxed);
+
+        self.release(len);
+    }
+
+    fn grow(&self, len: usize) {
+        self.len.fetch_add(len, SeqCst);
+
+        if self.waiters.load(SeqCst) > 0 {
+            let mut waiting = self.waiting.lock().unwrap();
+
+            if len == 1 {
+                self.available.notify_one();
+            } else {
+                self.available.notify_all();
+            }
+
+            let wakers = mem::take(&mut waiting.available);
+
+            drop(waiting);
+
+            wake(&self.waiters, wakers);
+        }
+    }
+}
+
+impl<T> Inner<T> {
+    fn inserted(&self, node: &Node<T>, position: Position) {
+        #[cfg(feature = "metrics")]
+        metrics::count(Counter::Push);
+
+        if self.hooked.load(SeqCst) {
+            let hooks = self.hooks.lock().unwrap().insert.clone();
+
+            fire(hooks, &node.value(), position);
+        }
+    }
+
+    fn removed(&self, value: &T, position: Position) {
+        #[cfg(feature = "metrics")]
+        metrics::count(Counter::Pop);
+
+        if self.hooked.load(SeqCst) {
+            let hooks = self.hooks.lock().unwrap().remove.clone();
+
+            fire(hooks, value, position);
+        }
+    }
+
+    fn node(&self, value: Arc<T>) -> Node<T> {
+        let storage = self.free.try_lock().ok().and_then(|mut free| free.pop());
+
+        match storage {
+            Some(routes) => Node::from_storage(value, routes),
+            None => Node::new_insulated_arc(value),
+        }
+    }
+
+    fn recycle(&self, node: Node<T>) {
+        let Some(routes) = node.into_storage() else {
+            return;
+        };
+
+        if let Ok(mut free) = self.free.try_lock() {
+            if free.len() < self.pool {
+                free.push(routes);
+            }
+        }
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        self.tail.get_mut().unwrap().take();
+
+        unlink_chain(self.head.get_mut().unwrap().take());
+    }
+}
+
+fn wake(waiters: &AtomicUsize, wakers: Vec<Waker>) {
+    waiters.fetch_sub(wakers.len(), SeqCst);
+
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+fn detach<T>(
+    head: &mut Option<Node<T>>,
+    tail: &mut Option<Node<T>>,
+    node: &Node<T>,
+) -> Option<Arc<T>> {
+    let is_head = head.as_ref() == Some(node);
+    let is_tail = tail.as_ref() == Some(node);
+
+    let value = if is_head && is_tail {
+        head.take();
+        tail.take();
+
+        node.disown();
+        node.value()
+    } else if is_head {
+        let (value, right) = node.insulate_right();
+
+        *head = right;
+
+        value
+    } else if is_tail {
+        let (value, left) = node.insulate_left();
+
+        *tail = left;
+
+        value
+    } else if node.is_insulate() {
+        return None;
+    } else {
+        node.insulate().0
+    };
+
+    value.into()
+}
+
+fn disown_chain<T>(first: &Node<T>, last: &Node<T>) {
+    let mut next = Some(first.clone());
+
+    while let Some(node) = next {
+        node.disown();
+
+        if node == *last {
+            break;
+        }
+
+        next = node.right();
+    }
+}
+
+fn attach_left<T>(head: &mut Option<Node<T>>, at: &Node<T>, node: &Node<T>) {
+    at.splice_left(node, node);
+
+    if head.as_ref() == Some(at) {
+        *head = node.clone().into();
+    }
+}
+
+fn attach_right<T>(tail: &mut Option<Node<T>>, at: &Node<T>, node: &Node<T>) {
+    at.splice_right(node, node);
+
+    if tail.as_ref() == Some(at) {
+        *tail = node.clone().into();
+    }
+}
+
+// The returned lock outlives the borrow of `node`. A node reached through a locked `right` link
+// stays linked, and so alive, while it is locked itself; the head lock keeps the bulk relinking in
+// sort, shuffle and merge out of the walk.
+unsafe fn routes<'a, T>(node: &Node<T>) -> &'a NodeLock<Routes<T>> {
+    unsafe { &*Arc::as_ptr(&node.routes) }
+}
+
+fn unlink_chain<T>(mut next: Option<Node<T>>) {
+    while let Some(node) = next {
+        next = node.unlink();
+    }
+}
+
+type Link<T> = fn(&mut Option<Node<T>>, &mut Option<Node<T>>, Node<T>);
+
+fn link_right<T>(chain: &mut Option<Node<T>>, last: &mut Option<Node<T>>, node: Node<T>) {
+    match last.as_ref() {
+        Some(last) => last.set_right(node.clone().into()),
+        None => *chain = node.clone().into(),
+    }
+
+    *last = node.into();
+}
+
+fn link_left<T>(chain: &mut Option<Node<T>>, last: &mut Option<Node<T>>, node: Node<T>) {
+    node.set_right(chain.take());
+
+    if last.is_none() {
+        *last = node.clone().into();
+    }
+
+    *chain = node.into();
+}
+
+fn link_lefts<T>(chain: Option<Node<T>>) -> Option<Node<T>> {
+    let mut left = None;
+    let mut next = chain;
+
+    while let Some(node) = next {
+        node.set_left(left);
+        next = node.right();
+        left = node.into();
+    }
+
+    left
+}
+
+pub(crate) struct Chain<T> {
+    pub first: Node<T>,
+    pub last: Node<T>,
+    pub len: usize,
+}
+
+fn flush<T>(
+    chain: &mut Option<Node<T>>,
+    last: &mut Option<Node<T>>,
+    len: usize,
+) -> Option<Chain<T>> {
+    link_lefts(chain.clone());
+
+    chain
+        .take()
+        .zip(last.take())
+        .map(|(first, last)| Chain { first, last, len })
+}
+
+// A merge pass in progress: the merged prefix, then `left_size` nodes from `left`, then everything
+// from `right` on. Dropping it, on completion or when the comparator panics, stitches those back
+// together in that order and puts them back at the front of the list.
+struct Sorting<'a, T> {
+    list: &'a SharedList<T>,
+    chain: Option<Node<T>>,
+    last: Option<Node<T>>,
+    left: Option<Node<T>>,
+    left_size: usize,
+    right: Option<Node<T>>,
+    len: usize,
+}
+
+impl<T> Drop for Sorting<'_, T> {
+    fn drop(&mut self) {
+        for _ in 0..mem::take(&mut self.left_size) {
+            let node = unsafe { self.left.take().unwrap_unchecked() };
+
+            self.left = node.right();
+
+            link_right(&mut self.chain, &mut self.last, node);
+        }
+
+        match self.right.take() {
+            Some(right) => link_right(&mut self.chain, &mut self.last, right),
+            None => {
+                if let Some(last) = self.last.as_ref() {
+                    last.set_right(None);
+                }
+            }
+        }
+
+        self.list.attach_front(self.chain.take(), self.len);
+    }
+}
+
+pub type ListHandle<T> = SharedList<T>;
+
+pub struct SharedList<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> SharedList<T> {
     pub fn new() -> Self {
         Self {
-            head: Arc::new(Mutex::new(None)),
-            tail: Arc::new(Mutex::new(None)),
+            inner: Arc::new(Inner::new(None, None, 0, usize::MAX)),
+        }
+    }
+
+    pub fn with_capacity_bound(bound: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner::new(None, None, 0, bound)),
+        }
+    }
+
+    /// Pre-allocates `capacity` node storages so the first `capacity` pushes take them from the
+    /// pool instead of the heap, and lets up to `capacity` popped nodes return to it for reuse.
+    pub fn with_node_pool(capacity: usize) -> Self {
+        let mut inner = Inner::new(None, None, 0, usize::MAX);
+
+        inner.pool = capacity;
+        *inner.free.get_mut().unwrap() = (0..capacity)
+            .map(|_| Arc::new(NodeLock::new(Routes::empty())))
+            .collect();
+
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    pub(crate) fn from_inner(inner: Arc<Inner<T>>) -> Self {
+        Self { inner }
+    }
+
+    pub(crate) fn from_chain(chain: Chain<T>) -> Self {
+        let list = Self {
+            inner: Arc::new(Inner::new(
+                chain.first.clone().into(),
+                chain.last.clone().into(),
+                chain.len,
+                usize::MAX,
+            )),
+        };
+
+        list.adopt_chain(&chain.first, &chain.last);
+
+        list
+    }
+
+    fn node(&self, value: Arc<T>) -> Node<T> {
+        let node = self.inner.node(value);
+
+        self.adopt(&node);
+
+        node
+    }
+
+    fn owns(&self, node: &Node<T>) -> bool {
+        node.is_owned_by(&self.inner)
+    }
+
+    fn adopt(&self, node: &Node<T>) {
+        node.adopt(Arc::downgrade(&self.inner));
+    }
+
+    fn adopt_chain(&self, first: &Node<T>, last: &Node<T>) {
+        let owner = Arc::downgrade(&self.inner);
+        let mut next = Some(first.clone());
+
+        while let Some(node) = next {
+            node.adopt(owner.clone());
+
+            if node == *last {
+                break;
+            }
+
+            next = node.right();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len.load(Relaxed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity_bound(&self) -> Option<usize> {
+        (self.inner.bound != usize::MAX).then_some(self.inner.bound)
+    }
+
+    pub fn close(&self) {
+        self.inner.closed.store(true, SeqCst);
+
+        let mut waiting = self.inner.waiting.lock().unwrap();
+
+        self.inner.available.notify_all();
+        self.inner.vacated.notify_all();
+
+        let available = mem::take(&mut waiting.available);
+        let vacated = mem::take(&mut waiting.vacated);
+
+        drop(waiting);
+
+        wake(&self.inner.waiters, available);
+        wake(&self.inner.blocked, vacated);
+    }
+
+    pub fn on_insert<F: Fn(&T, Position) + Send + Sync + 'static>(&self, f: F) {
+        self.inner.hooks.lock().unwrap().insert.push(Arc::new(f));
+        self.inner.hooked.store(true, SeqCst);
+    }
+
+    pub fn on_remove<F: Fn(&T, Position) + Send + Sync + 'static>(&self, f: F) {
+        self.inner.hooks.lock().unwrap().remove.push(Arc::new(f));
+        self.inner.hooked.store(true, SeqCst);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.inner.closed.load(SeqCst)
+    }
+
+    pub fn head(&self) -> Option<Node<T>> {
+        self.inner.head.lock().unwrap().clone()
+    }
+
+    pub fn tail(&self) -> Option<Node<T>> {
+        self.inner.tail.lock().unwrap().clone()
+    }
+
+    pub fn front(&self) -> Option<Arc<T>> {
+        self.inner
+            .head
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|node| node.value())
+    }
+
+    pub fn back(&self) -> Option<Arc<T>> {
+        self.inner
+            .tail
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|node| node.value())
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: self.node_iter(),
+        }
+    }
+
+    pub fn node_iter(&self) -> Nodes<'_, T> {
+        let tail = self.inner.tail.lock().unwrap();
+        let head = self.inner.head.lock().unwrap();
+
+        Nodes {
+            front: head.clone(),
+            back: tail.clone(),
+            len: self.len(),
+            _list: PhantomData,
+        }
+    }
+
+    pub fn drain(&self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        let head = self.head();
+        let index = head.as_ref().map(|_| 0);
+
+        Cursor::new(self, head, index)
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        let tail = self.tail();
+        let index = tail.as_ref().map(|_| self.len() - 1);
+
+        Cursor::new(self, tail, index)
+    }
+
+    pub fn cursor_front_mut(&self) -> CursorMut<'_, T> {
+        CursorMut::new(self.cursor_front())
+    }
+
+    pub fn cursor_back_mut(&self) -> CursorMut<'_, T> {
+        CursorMut::new(self.cursor_back())
+    }
+
+    pub fn push_front(&self, value: T) -> Result<Node<T>, Closed<T>> {
+        if !self.inner.claim() {
+            return Err(Closed(value));
+        }
+
+        let node = self.link_front(Arc::new(value));
+
+        self.inner.inserted(&node, Position::Front);
+
+        Ok(node)
+    }
+
+    fn link_front(&self, value: Arc<T>) -> Node<T> {
+        let node = self.node(value);
+        let backoff = Backoff::new();
+
+        loop {
+            backoff.snooze();
+
+            let mut head = self.inner.head.lock().unwrap();
+
+            if let Some(head) = head.as_mut() {
+                head.splice_left(&node, &node);
+
+                *head = node.clone();
+
+                self.inner.grow(1);
+
+                break node;
+            } else {
+                let mut tail = try_lock!(self.inner.tail);
+
+                *head = node.clone().into();
+                *tail = node.clone().into();
+
+                self.inner.grow(1);
+
+                break node;
+            }
         }
     }
 
-    pub fn head(&self) -> Option<Node<T>> {
-        self.head.lock().unwrap().clone()
-    }
+    pub fn push_back(&self, value: T) -> Result<Node<T>, Closed<T>> {
+        if !self.inner.claim() {
+            return Err(Closed(value));
+        }
+
+        let node = self.link_back(Arc::new(value));
+
+        self.inner.inserted(&node, Position::Back);
+
+        Ok(node)
+    }
+
+    pub(crate) fn push_back_arc(&self, value: Arc<T>) -> Result<Node<T>, Closed<Arc<T>>> {
+        if !self.inner.claim() {
+            return Err(Closed(value));
+        }
+
+        let node = self.link_back(value);
+
+        self.inner.inserted(&node, Position::Back);
+
+        Ok(node)
+    }
+
+    fn link_back(&self, value: Arc<T>) -> Node<T> {
+        let node = self.node(value);
+        let mut tail = self.inner.tail.lock().unwrap();
+
+        if let Some(tail) = tail.as_mut() {
+            tail.splice_right(&node, &node);
+
+            *tail = node.clone();
+
+            self.inner.grow(1);
+
+            node
+        } else {
+            let mut head = self.inner.head.lock().unwrap();
+
+            *head = node.clone().into();
+            *tail = node.clone().into();
+
+            self.inner.grow(1);
+
+            node
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<Arc<T>> {
+        self.get_node(index).map(|node| node.value())
+    }
+
+    pub fn get_node(&self, index: usize) -> Option<Node<T>> {
+        let len = self.len();
+
+        if index >= len {
+            return None;
+        }
+
+        if index < len / 2 {
+            let mut node = self.head();
+
+            for _ in 0..index {
+                node = node?.right();
+            }
+
+            node
+        } else {
+            let mut node = self.tail();
+
+            for _ in index + 1..len {
+                node = node?.left();
+            }
+
+            node
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| *item == *value)
+    }
+
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<Node<T>> {
+        let mut next = self.head();
+
+        while let Some(node) = next {
+            if predicate(&node.value()) {
+                return node.into();
+            }
+
+            next = node.right();
+        }
+
+        None
+    }
+
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<usize> {
+        self.iter().position(|value| predicate(&value))
+    }
+
+    pub fn min_node(&self) -> Option<Node<T>>
+    where
+        T: Ord,
+    {
+        self.node_iter().min_by(|a, b| a.value().cmp(&b.value()))
+    }
+
+    pub fn max_node(&self) -> Option<Node<T>>
+    where
+        T: Ord,
+    {
+        self.node_iter().max_by(|a, b| a.value().cmp(&b.value()))
+    }
+
+    pub fn min_node_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<Node<T>> {
+        self.node_iter().min_by_key(|node| f(&node.value()))
+    }
+
+    pub fn max_node_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<Node<T>> {
+        self.node_iter().max_by_key(|node| f(&node.value()))
+    }
+
+    pub fn position_of(&self, node: &Node<T>) -> Option<usize> {
+        let mut next = self.head();
+        let mut index = 0;
+
+        while let Some(current) = next {
+            if current == *node {
+                return index.into();
+            }
+
+            next = current.right();
+            index += 1;
+        }
+
+        None
+    }
+
+    pub fn try_push_front(&self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        self.try_link_front(value)
+            .inspect(|node| self.inner.inserted(node, Position::Front))
+    }
+
+    fn try_link_front(&self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        if self.is_closed() {
+            return Err(TryPushError::Closed(value));
+        }
+
+        if !self.inner.try_claim() {
+            return Err(TryPushError::Full(value));
+        }
+
+        let mut head = try_lock!(self.inner.head, {
+            self.inner.release(1);
+
+            return Err(TryPushError::WouldBlock(value));
+        });
+
+        if let Some(head) = head.as_mut() {
+            *head = head.try_insert_left(value).map_err(|value| {
+                self.inner.release(1);
+
+                TryPushError::WouldBlock(value)
+            })?;
+
+            self.adopt(head);
+            self.inner.grow(1);
+
+            Ok(head.clone())
+        } else {
+            let mut tail = try_lock!(self.inner.tail, {
+                self.inner.release(1);
+
+                return Err(TryPushError::WouldBlock(value));
+            });
+
+            let node = self.node(Arc::new(value));
+
+            *head = node.clone().into();
+            *tail = node.clone().into();
+
+            self.inner.grow(1);
+
+            Ok(node)
+        }
+    }
+
+    pub fn try_push_back(&self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        self.try_link_back(value)
+            .inspect(|node| self.inner.inserted(node, Position::Back))
+    }
+
+    fn try_link_back(&self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        if self.is_closed() {
+            return Err(TryPushError::Closed(value));
+        }
+
+        if !self.inner.try_claim() {
+            return Err(TryPushError::Full(value));
+        }
+
+        let mut tail = try_lock!(self.inner.tail, {
+            self.inner.release(1);
+
+            return Err(TryPushError::WouldBlock(value));
+        });
+
+        if let Some(tail) = tail.as_mut() {
+            *tail = tail.try_insert_right(value).map_err(|value| {
+                self.inner.release(1);
+
+                TryPushError::WouldBlock(value)
+            })?;
+
+            self.adopt(tail);
+            self.inner.grow(1);
+
+            Ok(tail.clone())
+        } else {
+            let mut head = try_lock!(self.inner.head, {
+                self.inner.release(1);
+
+                return Err(TryPushError::WouldBlock(value));
+            });
+
+            let node = self.node(Arc::new(value));
+
+            *head = node.clone().into();
+            *tail = node.clone().into();
+
+            self.inner.grow(1);
+
+            Ok(node)
+        }
+    }
+
+    pub fn insert(&self, index: usize, value: T) -> Result<Node<T>, InsertError<T>> {
+        let len = self.len();
+
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if index == 0 {
+            return Ok(self.push_front(value)?);
+        }
+
+        match self.get_node(index) {
+            Some(node) => self.insert_before(&node, value),
+            None => Ok(self.push_back(value)?),
+        }
+    }
+
+    pub fn insert_before(&self, node: &Node<T>, value: T) -> Result<Node<T>, InsertError<T>> {
+        if !self.inner.claim() {
+            return Err(InsertError::Closed(value));
+        }
+
+        let tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            self.inner.release(1);
+
+            return Err(InsertError::Detached(value));
+        }
+
+        let mid = node.insert_left(value);
+
+        self.adopt(&mid);
+
+        let position = if head.as_ref() == Some(node) {
+            *head = mid.clone().into();
+
+            Position::Front
+        } else {
+            Position::Middle
+        };
+
+        self.inner.grow(1);
+
+        drop(head);
+        drop(tail);
+
+        self.inner.inserted(&mid, position);
+
+        Ok(mid)
+    }
+
+    pub fn insert_after(&self, node: &Node<T>, value: T) -> Result<Node<T>, InsertError<T>> {
+        if !self.inner.claim() {
+            return Err(InsertError::Closed(value));
+        }
+
+        let mut tail = self.inner.tail.lock().unwrap();
+        let head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            self.inner.release(1);
+
+            return Err(InsertError::Detached(value));
+        }
+
+        let mid = node.insert_right(value);
+
+        self.adopt(&mid);
+
+        let position = if tail.as_ref() == Some(node) {
+            *tail = mid.clone().into();
+
+            Position::Back
+        } else {
+            Position::Middle
+        };
+
+        self.inner.grow(1);
+
+        drop(head);
+        drop(tail);
+
+        self.inner.inserted(&mid, position);
+
+        Ok(mid)
+    }
+
+    pub fn remove(&self, node: &Node<T>) -> Option<Arc<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            return None;
+        }
+
+        let position = if head.as_ref() == Some(node) {
+            Position::Front
+        } else if tail.as_ref() == Some(node) {
+            Position::Back
+        } else {
+            Position::Middle
+        };
+
+        let value = detach(&mut head, &mut tail, node)?;
+
+        self.inner.shrink(1);
+
+        drop(head);
+        drop(tail);
+
+        self.inner.removed(&value, position);
+
+        value.into()
+    }
+
+    pub fn move_to_front(&self, node: &Node<T>) -> bool {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            return false;
+        }
+
+        if head.as_ref() == Some(node) {
+            return true;
+        }
+
+        detach(&mut head, &mut tail, node);
+
+        if let Some(head) = head.as_ref() {
+            head.splice_left(node, node);
+        }
+
+        *head = node.clone().into();
+
+        self.adopt(node);
+
+        true
+    }
+
+    pub fn move_to_back(&self, node: &Node<T>) -> bool {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            return false;
+        }
+
+        if tail.as_ref() == Some(node) {
+            return true;
+        }
+
+        detach(&mut head, &mut tail, node);
+
+        if let Some(tail) = tail.as_ref() {
+            tail.splice_right(node, node);
+        }
+
+        *tail = node.clone().into();
+
+        self.adopt(node);
+
+        true
+    }
+
+    pub fn swap(&self, a: &Node<T>, b: &Node<T>) -> bool {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(a) || !self.owns(b) {
+            return false;
+        }
+
+        if a == b {
+            return true;
+        }
+
+        if a.right().as_ref() == Some(b) {
+            detach(&mut head, &mut tail, a);
+            attach_right(&mut tail, b, a);
+        } else if b.right().as_ref() == Some(a) {
+            detach(&mut head, &mut tail, b);
+            attach_right(&mut tail, a, b);
+        } else {
+            let left = a.left();
+            let right = a.right();
+
+            detach(&mut head, &mut tail, a);
+            attach_right(&mut tail, b, a);
+            detach(&mut head, &mut tail, b);
+
+            match (right, left) {
+                (Some(right), _) => attach_left(&mut head, &right, b),
+                (None, Some(left)) => attach_right(&mut tail, &left, b),
+                (None, None) => unreachable!(),
+            }
+        }
+
+        self.adopt(a);
+        self.adopt(b);
+
+        true
+    }
+
+    pub fn for_each_mut<F: FnMut(&mut T)>(&self, mut f: F)
+    where
+        T: Clone,
+    {
+        let mut next = self.head();
+
+        while let Some(node) = next {
+            next = node.right();
+
+            node.update(&mut f);
+        }
+    }
+
+    /// Calls `f` on every value from front to back without cloning node handles or values.
+    ///
+    /// The walk holds the head lock and locks nodes hand over hand, so `f` must not mutate the
+    /// list or its nodes, or call back into it at all; doing so deadlocks.
+    pub fn for_each_ref<F: FnMut(&T)>(&self, mut f: F) {
+        let head = self.inner.head.lock().unwrap();
+
+        let Some(first) = head.as_ref() else {
+            return;
+        };
+
+        let mut left = unsafe { routes(first) }.read().unwrap();
+
+        while let Some(next) = left.right.as_ref() {
+            let right = unsafe { routes(next) }.read().unwrap();
+
+            f(mem::replace(&mut left, right).value());
+        }
+
+        f(left.value());
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&self, mut f: F) {
+        let mut next = self.head();
+
+        while let Some(node) = next {
+            next = node.right();
+
+            if !f(&node.value()) {
+                self.remove(&node);
+            }
+        }
+    }
+
+    pub fn sort(&self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) {
+        let Some(chain) = self.detach_chain() else {
+            return;
+        };
+
+        let mut sort = Sorting {
+            list: self,
+            chain: None,
+            last: None,
+            left: None,
+            left_size: 0,
+            right: chain.first.into(),
+            len: chain.len,
+        };
+
+        let mut size = 1;
+
+        loop {
+            let mut merges = 0;
+
+            while sort.right.is_some() {
+                merges += 1;
+
+                sort.left = sort.right.clone();
+
+                while sort.left_size < size {
+                    sort.left_size += 1;
+                    sort.right = sort.right.take().and_then(|node| node.right());
+
+                    if sort.right.is_none() {
+                        break;
+                    }
+                }
+
+                let mut right_size = size;
+
+                while sort.left_size > 0 || (right_size > 0 && sort.right.is_some()) {
+                    let take_left = match (&sort.left, &sort.right) {
+                        (Some(l), Some(r)) if sort.left_size > 0 && right_size > 0 => {
+                            compare(&l.value(), &r.value()) != Ordering::Greater
+                        }
+                        _ => sort.left_size > 0,
+                    };
+
+                    let run = if take_left {
+                        sort.left_size -= 1;
+                        &mut sort.left
+                    } else {
+                        right_size -= 1;
+                        &mut sort.right
+                    };
+
+                    let node = unsafe { run.take().unwrap_unchecked() };
+
+                    *run = node.right();
+
+                    link_right(&mut sort.chain, &mut sort.last, node);
+                }
+            }
+
+            if let Some(last) = sort.last.as_ref() {
+                last.set_right(None);
+            }
+
+            if merges <= 1 {
+                break;
+            }
+
+            sort.right = sort.chain.take();
+            sort.last = None;
+            size *= 2;
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn shuffle<R: Rng + ?Sized>(&self, rng: &mut R) {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let mut nodes = Vec::with_capacity(self.len());
+        let mut next = head.take();
+
+        while let Some(node) = next {
+            next = node.right();
+            nodes.push(node);
+        }
+
+        nodes.shuffle(rng);
+
+        let mut chain = None;
+        let mut last = None;
+
+        for node in nodes {
+            link_right(&mut chain, &mut last, node);
+        }
+
+        if let Some(last) = last.as_ref() {
+            last.set_right(None);
+        }
+
+        *tail = link_lefts(chain.clone());
+        *head = chain;
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Arc<T>> {
+        let len = self.len();
+
+        if len == 0 {
+            return None;
+        }
+
+        self.get(rng.gen_range(0..len))
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<Arc<T>> {
+        let mut reservoir = Vec::with_capacity(n.min(self.len()));
+
+        for (i, value) in self.iter().enumerate() {
+            if i < n {
+                reservoir.push(value);
+            } else {
+                let j = rng.gen_range(0..=i);
+
+                if j < n {
+                    reservoir[j] = value;
+                }
+            }
+        }
+
+        reservoir
+    }
+
+    pub fn merge(&self, other: &SharedList<T>)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, |a, b| a.cmp(b));
+    }
+
+    pub fn merge_by<F: FnMut(&T, &T) -> Ordering>(&self, other: &SharedList<T>, mut compare: F) {
+        if self.ptr_eq(other) {
+            return;
+        }
+
+        let Some(other) = other.take_chain() else {
+            return;
+        };
+
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        self.adopt_chain(&other.first, &other.last);
+
+        let mut left = head.take();
+        let mut right = Some(other.first);
+        let mut chain = None;
+        let mut last = None;
+
+        while left.is_some() || right.is_some() {
+            let take_left = match (&left, &right) {
+                (Some(l), Some(r)) => compare(&l.value(), &r.value()) != Ordering::Greater,
+                (l, _) => l.is_some(),
+            };
+
+            let run = if take_left { &mut left } else { &mut right };
+
+            let node = unsafe { run.take().unwrap_unchecked() };
+
+            *run = node.right();
+
+            link_right(&mut chain, &mut last, node);
+        }
+
+        *tail = link_lefts(chain.clone());
+        *head = chain;
+
+        self.inner.claimed.fetch_add(other.len, SeqCst);
+        self.inner.grow(other.len);
+    }
+
+    pub fn remove_at(&self, index: usize) -> Option<Arc<T>> {
+        self.remove(&self.get_node(index)?)
+    }
+
+    pub(crate) fn ptr_eq(&self, other: &SharedList<T>) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    pub(crate) fn take_chain(&self) -> Option<Chain<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let len = self.len();
+
+        self.inner.shrink(len);
+
+        let (first, last) = head.take().zip(tail.take())?;
+
+        disown_chain(&first, &last);
+
+        Some(Chain { first, last, len })
+    }
+
+    fn detach_chain(&self) -> Option<Chain<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let (first, last) = head.take().zip(tail.take())?;
+        let len = self.inner.len.swap(0, Relaxed);
+
+        disown_chain(&first, &last);
+
+        Some(Chain { first, last, len })
+    }
+
+    fn attach_front(&self, chain: Option<Node<T>>, len: usize) {
+        let Some((first, last)) = chain.clone().zip(link_lefts(chain)) else {
+            return;
+        };
+
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        self.adopt_chain(&first, &last);
+
+        match head.as_ref() {
+            Some(head) => head.splice_left(&first, &last),
+            None => *tail = last.into(),
+        }
+
+        *head = first.into();
+
+        self.inner.grow(len);
+    }
+
+    pub(crate) fn take_segment(
+        &self,
+        first: &Node<T>,
+        last: &Node<T>,
+    ) -> Option<(Chain<T>, Option<Node<T>>)> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let mut next = head.clone();
+
+        while next.as_ref().is_some_and(|node| node != first) {
+            next = next.and_then(|node| node.right());
+        }
+
+        let mut len = 0;
+
+        loop {
+            let node = next?;
+
+            len += 1;
+
+            if node == *last {
+                break;
+            }
+
+            next = node.right();
+        }
+
+        let left = first.split_left();
+        let right = last.right();
+
+        if let Some(right) = right.as_ref() {
+            right.split_left();
+        }
+
+        match (left.as_ref(), right.as_ref()) {
+            (Some(left), Some(right)) => left.splice_right(right, right),
+            (Some(_), None) => *tail = left,
+            (None, Some(_)) => *head = right.clone(),
+            (None, None) => {
+                head.take();
+                tail.take();
+            }
+        }
+
+        self.inner.shrink(len);
+
+        disown_chain(first, last);
+
+        let chain = Chain {
+            first: first.clone(),
+            last: last.clone(),
+            len,
+        };
+
+        Some((chain, right))
+    }
+
+    pub(crate) fn splice_before(&self, at: &Node<T>, chain: Chain<T>) -> Result<(), Chain<T>> {
+        let _tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(at) {
+            return Err(chain);
+        }
+
+        let Chain { first, last, len } = chain;
+
+        self.adopt_chain(&first, &last);
+
+        at.splice_left(&first, &last);
+
+        if head.as_ref() == Some(at) {
+            *head = first.into();
+        }
+
+        self.inner.claimed.fetch_add(len, SeqCst);
+        self.inner.grow(len);
+
+        Ok(())
+    }
+
+    pub(crate) fn splice_back(&self, chain: Chain<T>) {
+        let Chain { first, last, len } = chain;
+
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        self.adopt_chain(&first, &last);
+
+        match tail.as_mut() {
+            Some(tail) => {
+                tail.splice_right(&first, &last);
+
+                *tail = last;
+            }
+            None => {
+                *head = first.into();
+                *tail = last.into();
+            }
+        }
+
+        self.inner.claimed.fetch_add(len, SeqCst);
+        self.inner.grow(len);
+    }
+
+    pub fn append(&self, other: &SharedList<T>) {
+        if self.ptr_eq(other) {
+            return;
+        }
+
+        if let Some(chain) = other.take_chain() {
+            self.splice_back(chain);
+        }
+    }
+
+    pub fn concat<I: IntoIterator<Item = SharedList<T>>>(lists: I) -> SharedList<T> {
+        let list = SharedList::new();
+
+        for other in lists {
+            list.append(&other);
+        }
+
+        list
+    }
+
+    pub fn interleave(&self, other: &SharedList<T>) -> SharedList<T> {
+        let chains = [self.take_chain(), other.take_chain()];
+
+        let len = chains.iter().flatten().map(|chain| chain.len).sum();
+
+        let mut runs = chains.map(|chain| chain.map(|chain| chain.first));
+        let mut chain = None;
+        let mut last = None;
+        let mut turn = 0;
+
+        while runs.iter().any(Option::is_some) {
+            if runs[turn].is_none() {
+                turn ^= 1;
+            }
+
+            let node = unsafe { runs[turn].take().unwrap_unchecked() };
+
+            runs[turn] = node.right();
+
+            link_right(&mut chain, &mut last, node);
+
+            turn ^= 1;
+        }
+
+        match flush(&mut chain, &mut last, len) {
+            Some(chain) => SharedList::from_chain(chain),
+            None => SharedList::new(),
+        }
+    }
+
+    pub fn splice(
+        &self,
+        at: &Node<T>,
+        from: &SharedList<T>,
+        first: &Node<T>,
+        last: &Node<T>,
+    ) -> bool {
+        if self.ptr_eq(from) {
+            return false;
+        }
+
+        let Some((chain, right)) = from.take_segment(first, last) else {
+            return false;
+        };
+
+        let Err(chain) = self.splice_before(at, chain) else {
+            return true;
+        };
+
+        let chain = match right {
+            Some(right) => from.splice_before(&right, chain),
+            None => Err(chain),
+        };
+
+        if let Err(chain) = chain {
+            from.splice_back(chain);
+        }
+
+        false
+    }
+
+    pub fn split_off(&self, node: &Node<T>) -> SharedList<T> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if !self.owns(node) {
+            return SharedList::new();
+        }
+
+        let last = if head.as_ref() == Some(node) {
+            head.take();
+            tail.take()
+        } else if let Some(left) = node.split_left() {
+            tail.replace(left)
+        } else {
+            None
+        };
+
+        match last {
+            Some(last) => {
+                let len = node.clone().into_iter().count();
+
+                self.inner.shrink(len);
+
+                SharedList::from_chain(Chain {
+                    first: node.clone(),
+                    last,
+                    len,
+                })
+            }
+            None => SharedList::new(),
+        }
+    }
+
+    pub fn split_at(self, index: usize) -> (SharedList<T>, SharedList<T>) {
+        let len = self.len();
+
+        assert!(
+            index <= len,
+            "split index (is {index}) should be <= len (is {len})"
+        );
+
+        let right = match self.get_node(index) {
+            Some(node) => self.split_off(&node),
+            None => SharedList::new(),
+        };
+
+        (self, right)
+    }
+
+    pub fn truncate(&self, len: usize) {
+        if let Some(node) = self.get_node(len) {
+            self.split_off(&node).clear();
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Some(chain) = self.take_chain() {
+            unlink_chain(chain.first.into());
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let tail = self.inner.tail.lock().unwrap();
+        let head = self.inner.head.lock().unwrap();
+
+        let len = self.len();
+
+        let (head, tail) = match (head.as_ref(), tail.as_ref()) {
+            (Some(head), Some(tail)) => (head, tail),
+            (None, None) if len == 0 => return Ok(()),
+            (None, None) => {
+                return Err(ValidationError::LengthMismatch {
+                    expected: len,
+                    found: 0,
+                })
+            }
+            _ => return Err(ValidationError::DanglingEnd),
+        };
+
+        if head.left().is_some() {
+            return Err(ValidationError::HeadHasLeft);
+        }
+
+        let mut node = head.clone();
+        let mut found = 1;
+
+        while let Some(right) = node.right() {
+            if right.left().as_ref() != Some(&node) {
+                return Err(ValidationError::Asymmetric { index: found - 1 });
+            }
+
+            if found == len {
+                return Err(ValidationError::LengthMismatch {
+                    expected: len,
+                    found: found + 1,
+                });
+            }
+
+            node = right;
+            found += 1;
+        }
+
+        if node != *tail {
+            return Err(ValidationError::TailMismatch { index: found - 1 });
+        }
+
+        if found != len {
+            return Err(ValidationError::LengthMismatch {
+                expected: len,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> Snapshot<T> {
+        let tail = self.inner.tail.lock().unwrap();
+        let head = self.inner.head.lock().unwrap();
+
+        let mut values = Vec::with_capacity(self.len());
+        let mut next = head.clone();
+
+        while let Some(node) = next {
+            values.push(node.value());
+
+            if tail.as_ref() == Some(&node) {
+                break;
+            }
+
+            next = node.right();
+        }
+
+        Snapshot { values }
+    }
+
+    pub fn transaction<R, F: FnOnce(&mut Transaction<'_, T>) -> R>(&self, f: F) -> R {
+        let tail = self.inner.tail.lock().unwrap();
+        let head = self.inner.head.lock().unwrap();
+
+        let mut txn = Transaction {
+            list: self,
+            head,
+            tail,
+            events: Vec::new(),
+        };
+
+        let result = f(&mut txn);
+
+        let events = mem::take(&mut txn.events);
+
+        drop(txn);
+
+        for event in events {
+            match event {
+                Event::Inserted(node, position) => self.inner.inserted(&node, position),
+                Event::Removed(value, position) => self.inner.removed(&value, position),
+            }
+        }
+
+        result
+    }
+
+    pub fn handle(&self) -> ListHandle<T> {
+        self.clone()
+    }
+
+    pub fn deep_clone(&self) -> SharedList<T> {
+        let list = SharedList::with_capacity_bound(self.inner.bound);
+
+        for value in self.snapshot() {
+            let _ = list.push_back_arc(value);
+        }
+
+        list
+    }
+
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, f: F) -> ChunkBy<'_, T, F> {
+        ChunkBy {
+            iter: self.iter(),
+            next: None,
+            f,
+        }
+    }
+
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+
+        Windows {
+            iter: self.iter(),
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<Arc<T>> {
+        self.iter().collect()
+    }
+
+    pub fn join(&self, separator: &str) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut joined = String::new();
+
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                joined.push_str(separator);
+            }
+
+            let _ = fmt::write(&mut joined, format_args!("{value}"));
+        }
+
+        joined
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> SharedList<U> {
+        self.iter().map(|value| f(&value)).collect()
+    }
+
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut f: F) -> SharedList<T> {
+        self.iter().filter(|value| f(value)).collect()
+    }
+
+    pub fn zip<U>(&self, other: &SharedList<U>) -> SharedList<(Arc<T>, Arc<U>)> {
+        self.iter().zip(other.iter()).collect()
+    }
+
+    pub fn partition<F: FnMut(&T) -> bool>(&self, mut f: F) -> (SharedList<T>, SharedList<T>) {
+        let matched = SharedList::new();
+        let rest = SharedList::new();
+
+        for value in self.iter() {
+            let list = if f(&value) { &matched } else { &rest };
+
+            let _ = list.push_back_arc(value);
+        }
+
+        (matched, rest)
+    }
+
+    pub fn into_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::new();
+
+        while let Some(value) = self.pop_front() {
+            vec.push(Arc::unwrap_or_clone(value));
+        }
+
+        vec
+    }
+
+    pub fn into_vecdeque(self) -> VecDeque<T>
+    where
+        T: Clone,
+    {
+        self.into_vec().into()
+    }
+
+    pub fn into_owned(self) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut list = LinkedList::new();
+
+        while let Some(value) = self.pop_front() {
+            list.push_back(Arc::unwrap_or_clone(value));
+        }
+
+        list
+    }
+
+    pub fn extend_from_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> Result<(), Closed<T>> {
+        self.push_back_all(iter)
+    }
+
+    pub fn push_back_all<I: IntoIterator<Item = T>>(&self, iter: I) -> Result<(), Closed<T>> {
+        self.push_all(iter, link_right, Self::link_chain_back, Position::Back)
+    }
+
+    pub fn push_front_all<I: IntoIterator<Item = T>>(&self, iter: I) -> Result<(), Closed<T>> {
+        self.push_all(iter, link_left, Self::link_chain_front, Position::Front)
+    }
+
+    fn push_all<I: IntoIterator<Item = T>>(
+        &self,
+        iter: I,
+        link: Link<T>,
+        splice: fn(&Self, Chain<T>),
+        position: Position,
+    ) -> Result<(), Closed<T>> {
+        let mut nodes = self.inner.hooked.load(SeqCst).then(Vec::new);
+        let mut chain = None;
+        let mut last = None;
+        let mut len = 0;
+        let mut result = Ok(());
+
+        for value in iter {
+            if self.inner.closed.load(SeqCst) || !self.inner.try_claim() {
+                if let Some(chain) = flush(&mut chain, &mut last, mem::take(&mut len)) {
+                    splice(self, chain);
+                }
+
+                if !self.inner.claim() {
+                    result = Err(Closed(value));
+
+                    break;
+                }
+            }
+
+            let node = self.node(Arc::new(value));
+
+            match nodes.as_mut() {
+                Some(nodes) => nodes.push(node.clone()),
+                #[cfg(feature = "metrics")]
+                None => metrics::count(Counter::Push),
+                #[cfg(not(feature = "metrics"))]
+                None => {}
+            }
+
+            link(&mut chain, &mut last, node);
+
+            len += 1;
+        }
+
+        if let Some(chain) = flush(&mut chain, &mut last, len) {
+            splice(self, chain);
+        }
+
+        for node in nodes.iter().flatten() {
+            self.inner.inserted(node, position);
+        }
+
+        result
+    }
+
+    fn link_chain_back(&self, chain: Chain<T>) {
+        let Chain { first, last, len } = chain;
+
+        let mut tail = self.inner.tail.lock().unwrap();
+
+        if let Some(tail) = tail.as_mut() {
+            tail.splice_right(&first, &last);
+
+            *tail = last;
+        } else {
+            let mut head = self.inner.head.lock().unwrap();
+
+            *head = first.into();
+            *tail = last.into();
+        }
+
+        self.inner.grow(len);
+    }
+
+    fn link_chain_front(&self, chain: Chain<T>) {
+        let Chain { first, last, len } = chain;
+        let backoff = Backoff::new();
+
+        loop {
+            backoff.snooze();
+
+            let mut head = self.inner.head.lock().unwrap();
+
+            if let Some(head) = head.as_mut() {
+                head.splice_left(&first, &last);
+
+                *head = first;
+            } else {
+                let mut tail = try_lock!(self.inner.tail);
+
+                *head = first.into();
+                *tail = last.into();
+            }
+
+            self.inner.grow(len);
+
+            break;
+        }
+    }
+
+    pub fn pop_front(&self) -> Option<Arc<T>> {
+        self.unlink_front()
+            .inspect(|value| self.inner.removed(value, Position::Front))
+    }
+
+    fn unlink_front(&self) -> Option<Arc<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        if *tail == *head {
+            if let Some(head) = head.take() {
+                tail.take();
+
+                self.inner.shrink(1);
+
+                let value = head.value();
+
+                head.disown();
+
+                self.inner.recycle(head);
+
+                value.into()
+            } else {
+                None
+            }
+        } else {
+            drop(tail);
+
+            let head = unsafe { head.as_mut().unwrap_unchecked() };
+
+            let (value, right) = head.insulate_right();
+
+            let node = mem::replace(head, unsafe { right.unwrap_unchecked() });
+
+            self.inner.shrink(1);
+            self.inner.recycle(node);
+
+            value.into()
+        }
+    }
+
+    pub fn pop_back(&self) -> Option<Arc<T>> {
+        self.unlink_back()
+            .inspect(|value| self.inner.removed(value, Position::Back))
+    }
+
+    fn unlink_back(&self) -> Option<Arc<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+
+        let node = tail.take()?;
+
+        let (value, left) = node.insulate_left();
+
+        if left.is_some() {
+            *tail = left;
+        } else {
+            self.inner.head.lock().unwrap().take();
+        }
+
+        self.inner.shrink(1);
+        self.inner.recycle(node);
+
+        value.into()
+    }
+
+    pub fn pop_front_n(&self, n: usize) -> Vec<Arc<T>> {
+        let values = self.unlink_front_n(n);
+
+        for value in &values {
+            self.inner.removed(value, Position::Front);
+        }
+
+        values
+    }
+
+    fn unlink_front_n(&self, n: usize) -> Vec<Arc<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let len = self.len().min(n);
+
+        let Some(first) = head.clone().filter(|_| len > 0) else {
+            return Vec::new();
+        };
+
+        if len == self.len() {
+            head.take();
+            tail.take();
+        } else {
+            let right = first.nth_right(len);
+
+            if let Some(right) = right.as_ref() {
+                right.split_left();
+            }
+
+            *head = right;
+        }
+
+        self.inner.shrink(len);
+
+        self.unlink_values(first, len)
+    }
+
+    pub fn pop_back_n(&self, n: usize) -> Vec<Arc<T>> {
+        let values = self.unlink_back_n(n);
+
+        for value in &values {
+            self.inner.removed(value, Position::Back);
+        }
+
+        values
+    }
+
+    fn unlink_back_n(&self, n: usize) -> Vec<Arc<T>> {
+        let mut tail = self.inner.tail.lock().unwrap();
+        let mut head = self.inner.head.lock().unwrap();
+
+        let len = self.len().min(n);
+
+        let Some(first) = tail
+            .as_ref()
+            .and_then(|node| node.nth_left(len.checked_sub(1)?))
+        else {
+            return Vec::new();
+        };
+
+        if len == self.len() {
+            head.take();
+            tail.take();
+        } else {
+            *tail = first.split_left();
+        }
+
+        self.inner.shrink(len);
+
+        let mut values = self.unlink_values(first, len);
+
+        values.reverse();
+
+        values
+    }
+
+    fn unlink_values(&self, first: Node<T>, len: usize) -> Vec<Arc<T>> {
+        let mut values = Vec::with_capacity(len);
+        let mut next = Some(first);
+
+        while let Some(node) = next {
+            values.push(node.value());
+            next = node.unlink();
+
+            self.inner.recycle(node);
+        }
+
+        values
+    }
+
+    pub(crate) fn steal_front(&self) -> Option<Arc<T>> {
+        let mut head = self.inner.head.lock().unwrap();
+
+        let Some((value, right)) = head.as_ref().and_then(|node| node.steal_right()) else {
+            drop(head);
+
+            return self.pop_front();
+        };
+
+        let node = mem::replace(head.as_mut()?, right);
+
+        self.inner.shrink(1);
+
+        drop(head);
+
+        self.inner.recycle(node);
+        self.inner.removed(&value, Position::Front);
+
+        value.into()
+    }
+
+    pub fn try_pop_front(&self) -> Result<Option<Arc<T>>, WouldBlock> {
+        self.try_unlink_front().inspect(|value| {
+            if let Some(value) = value {
+                self.inner.removed(value, Position::Front);
+            }
+        })
+    }
+
+    fn try_unlink_front(&self) -> Result<Option<Arc<T>>, WouldBlock> {
+        let mut tail = try_lock!(self.inner.tail, return Err(WouldBlock));
+        let mut head = try_lock!(self.inner.head, return Err(WouldBlock));
+
+        if *tail == *head {
+            tail.take();
+
+            let value = head.take().map(|head| {
+                head.disown();
+                head.value()
+            });
+
+            if value.is_some() {
+                self.inner.shrink(1);
+            }
+
+            Ok(value)
+        } else {
+            drop(tail);
+
+            let Some(node) = head.as_ref() else {
+                return Ok(None);
+            };
+
+            let (value, right) = node.try_insulate_right().ok_or(WouldBlock)?;
+
+            *head = right;
+
+            self.inner.shrink(1);
+
+            Ok(value.into())
+        }
+    }
+
+    pub fn try_pop_back(&self) -> Result<Option<Arc<T>>, WouldBlock> {
+        self.try_unlink_back().inspect(|value| {
+            if let Some(value) = value {
+                self.inner.removed(value, Position::Back);
+            }
+        })
+    }
+
+    fn try_unlink_back(&self) -> Result<Option<Arc<T>>, WouldBlock> {
+        let mut tail = try_lock!(self.inner.tail, return Err(WouldBlock));
+        let mut head = try_lock!(self.inner.head, return Err(WouldBlock));
+
+        if *tail == *head {
+            head.take();
+
+            let value = tail.take().map(|tail| {
+                tail.disown();
+                tail.value()
+            });
+
+            if value.is_some() {
+                self.inner.shrink(1);
+            }
+
+            Ok(value)
+        } else {
+            drop(head);
+
+            let Some(node) = tail.as_ref() else {
+                return Ok(None);
+            };
+
+            let (value, left) = node.try_insulate_left().ok_or(WouldBlock)?;
+
+            *tail = left;
+
+            self.inner.shrink(1);
+
+            Ok(value.into())
+        }
+    }
+
+    blocking! {
+        fn pop_wait<'a>(
+            &'a self,
+            pop: fn(&Self) -> Option<Arc<T>>,
+            mut wait: impl FnMut(MutexGuard<'a, Waiting>) -> Option<MutexGuard<'a, Waiting>>,
+        ) -> Option<Arc<T>> {
+            loop {
+                if let Some(value) = pop(self) {
+                    return value.into();
+                }
+
+                let mut waiting = self.inner.waiting.lock().unwrap();
+
+                self.inner.waiters.fetch_add(1, SeqCst);
+
+                while self.inner.len.load(SeqCst) == 0 {
+                    if self.is_closed() {
+                        self.inner.waiters.fetch_sub(1, SeqCst);
+
+                        return None;
+                    }
+
+                    match wait(waiting) {
+                        Some(guard) => waiting = guard,
+                        None => {
+                            self.inner.waiters.fetch_sub(1, SeqCst);
+
+                            return None;
+                        }
+                    }
+                }
+
+                self.inner.waiters.fetch_sub(1, SeqCst);
+            }
+        }
+
+        fn wait<'a>(&self, waiting: MutexGuard<'a, Waiting>) -> Option<MutexGuard<'a, Waiting>> {
+            self.inner.available.wait(waiting).unwrap().into()
+        }
+
+        fn wait_until<'a>(
+            &'a self,
+            timeout: Duration,
+        ) -> impl FnMut(MutexGuard<'a, Waiting>) -> Option<MutexGuard<'a, Waiting>> {
+            let deadline = Instant::now().checked_add(timeout);
+
+            move |waiting| match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+
+                    if now >= deadline {
+                        return None;
+                    }
+
+                    let (waiting, _) = self
+                        .inner
+                        .available
+                        .wait_timeout(waiting, deadline - now)
+                        .unwrap();
+
+                    waiting.into()
+                }
+                None => self.wait(waiting),
+            }
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    pub(crate) fn poll_pop_front(&self, cx: &mut Context<'_>) -> Poll<Option<Arc<T>>> {
+        loop {
+            let closed = self.is_closed();
+
+            if let Some(value) = self.pop_front() {
+                return Poll::Ready(value.into());
+            }
+
+            if closed {
+                return Poll::Ready(None);
+            }
+
+            let mut waiting = self.inner.waiting.lock().unwrap();
+
+            if !waiting
+                .available
+                .iter()
+                .any(|waker| waker.will_wake(cx.waker()))
+            {
+                waiting.available.push(cx.waker().clone());
+
+                self.inner.waiters.fetch_add(1, SeqCst);
+            }
+
+            if self.inner.len.load(SeqCst) == 0 && !self.is_closed() {
+                return Poll::Pending;
+            }
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    pub(crate) fn poll_claim(&self, cx: &mut Context<'_>) -> Poll<Result<(), Closed<()>>> {
+        loop {
+            if self.is_closed() {
+                return Poll::Ready(Err(Closed(())));
+            }
+
+            if self.inner.try_claim() {
+                return Poll::Ready(Ok(()));
+            }
+
+            let mut waiting = self.inner.waiting.lock().unwrap();
+
+            if !waiting
+                .vacated
+                .iter()
+                .any(|waker| waker.will_wake(cx.waker()))
+            {
+                waiting.vacated.push(cx.waker().clone());
+
+                self.inner.blocked.fetch_add(1, SeqCst);
+            }
+
+            if self.inner.claimed.load(SeqCst) >= self.inner.bound && !self.is_closed() {
+                return Poll::Pending;
+            }
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    pub(crate) fn push_back_claimed(&self, value: T) -> Node<T> {
+        let node = self.link_back(Arc::new(value));
+
+        self.inner.inserted(&node, Position::Back);
+
+        node
+    }
+
+    #[cfg(feature = "futures")]
+    pub(crate) fn release_claim(&self) {
+        self.inner.release(1);
+    }
+
+    blocking! {
+        pub fn pop_front_blocking(&self) -> Option<Arc<T>> {
+            self.pop_wait(Self::pop_front, |waiting| self.wait(waiting))
+        }
+
+        pub fn pop_back_blocking(&self) -> Option<Arc<T>> {
+            self.pop_wait(Self::pop_back, |waiting| self.wait(waiting))
+        }
+
+        pub fn pop_front_timeout(&self, timeout: Duration) -> Option<Arc<T>> {
+            self.pop_wait(Self::pop_front, self.wait_until(timeout))
+        }
+
+        pub fn pop_back_timeout(&self, timeout: Duration) -> Option<Arc<T>> {
+            self.pop_wait(Self::pop_back, self.wait_until(timeout))
+        }
+    }
+
+    pub fn pop_front_owned(&self) -> Option<Result<T, Arc<T>>> {
+        self.pop_front().map(Arc::try_unwrap)
+    }
+
+    pub fn pop_back_owned(&self) -> Option<Result<T, Arc<T>>> {
+        self.pop_back().map(Arc::try_unwrap)
+    }
+}
+
+impl<T> Default for SharedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for SharedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for SharedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let list = Self::new();
+
+        let _ = list.extend_from_iter(iter);
+
+        list
+    }
+}
+
+impl<T> FromIterator<Arc<T>> for SharedList<T> {
+    fn from_iter<I: IntoIterator<Item = Arc<T>>>(iter: I) -> Self {
+        let list = Self::new();
+
+        for value in iter {
+            let _ = list.push_back_arc(value);
+        }
+
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SharedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            value.fmt(f)?;
+        }
+
+        f.write_str("]")
+    }
+}
+
+impl<T: PartialEq> PartialEq for SharedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SharedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for SharedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for SharedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for SharedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut len = 0;
+
+        for value in self.iter() {
+            value.hash(state);
+            len += 1;
+        }
+
+        state.write_usize(len);
+    }
+}
+
+impl<T> From<Vec<T>> for SharedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for SharedList<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> From<ArenaList<T>> for SharedList<T> {
+    fn from(list: ArenaList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<SharedList<T>> for ArenaList<Arc<T>> {
+    fn from(list: SharedList<T>) -> Self {
+        list.snapshot().into_iter().collect()
+    }
+}
+
+impl<T> Extend<T> for SharedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let _ = self.extend_from_iter(iter);
+    }
+}
+
+pub struct Nodes<'a, T> {
+    front: Option<Node<T>>,
+    back: Option<Node<T>>,
+    len: usize,
+    _list: PhantomData<&'a SharedList<T>>,
+}
+
+impl<T> Iterator for Nodes<'_, T> {
+    type Item = Node<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.front.take()?;
+
+        if self.back.as_ref() == Some(&node) {
+            self.back = None;
+        } else {
+            self.front = node.right();
+        }
+
+        self.len -= 1;
+
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Nodes<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.back.take()?;
+
+        if self.front.as_ref() == Some(&node) {
+            self.front = None;
+        } else {
+            self.back = node.left();
+        }
+
+        self.len -= 1;
+
+        Some(node)
+    }
+}
+
+pub struct Iter<'a, T> {
+    nodes: Nodes<'a, T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = Arc<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.next().map(|node| node.value())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nodes.next_back().map(|node| node.value())
+    }
+}
+
+pub struct ChunkBy<'a, T, F> {
+    iter: Iter<'a, T>,
+    next: Option<Arc<T>>,
+    f: F,
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<'_, T, F> {
+    type Item = Vec<Arc<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::from([self.next.take().or_else(|| self.iter.next())?]);
+
+        for value in self.iter.by_ref() {
+            if !(self.f)(&chunk[chunk.len() - 1], &value) {
+                self.next = value.into();
+
+                break;
+            }
+
+            chunk.push(value);
+        }
+
+        chunk.into()
+    }
+}
+
+pub struct Windows<'a, T> {
+    iter: Iter<'a, T>,
+    window: VecDeque<Arc<T>>,
+    size: usize,
+}
+
+impl<T> Iterator for Windows<'_, T> {
+    type Item = Vec<Arc<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        self.window.iter().cloned().collect::<Vec<_>>().into()
+    }
+}
+
+pub struct Snapshot<T> {
+    values: Vec<Arc<T>>,
+}
+
+impl<T> Deref for Snapshot<T> {
+    type Target = [Arc<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Snapshot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.values.iter()).finish()
+    }
+}
+
+impl<T> IntoIterator for Snapshot<T> {
+    type Item = Arc<T>;
+    type IntoIter = vec::IntoIter<Arc<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Snapshot<T> {
+    type Item = &'a Arc<T>;
+    type IntoIter = core::slice::Iter<'a, Arc<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+enum Event<T> {
+    Inserted(Node<T>, Position),
+    Removed(Arc<T>, Position),
+}
+
+pub struct Transaction<'a, T> {
+    list: &'a SharedList<T>,
+    head: MutexGuard<'a, Option<Node<T>>>,
+    tail: MutexGuard<'a, Option<Node<T>>>,
+    events: Vec<Event<T>>,
+}
+
+impl<T> Transaction<'_, T> {
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn front(&self) -> Option<Arc<T>> {
+        self.head.as_ref().map(|node| node.value())
+    }
+
+    pub fn back(&self) -> Option<Arc<T>> {
+        self.tail.as_ref().map(|node| node.value())
+    }
+
+    fn claim(&self, value: T) -> Result<Arc<T>, TryPushError<T>> {
+        let inner = &self.list.inner;
+
+        if inner.closed.load(SeqCst) {
+            Err(TryPushError::Closed(value))
+        } else if !inner.try_claim() {
+            Err(TryPushError::Full(value))
+        } else {
+            Ok(Arc::new(value))
+        }
+    }
+
+    pub fn push_front(&mut self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        let node = self.list.node(self.claim(value)?);
+
+        match self.head.as_mut() {
+            Some(head) => {
+                head.splice_left(&node, &node);
+
+                *head = node.clone();
+            }
+            None => {
+                *self.head = node.clone().into();
+                *self.tail = node.clone().into();
+            }
+        }
+
+        self.list.inner.grow(1);
+        self.events
+            .push(Event::Inserted(node.clone(), Position::Front));
+
+        Ok(node)
+    }
+
+    pub fn push_back(&mut self, value: T) -> Result<Node<T>, TryPushError<T>> {
+        let node = self.list.node(self.claim(value)?);
+
+        match self.tail.as_mut() {
+            Some(tail) => {
+                tail.splice_right(&node, &node);
+
+                *tail = node.clone();
+            }
+            None => {
+                *self.head = node.clone().into();
+                *self.tail = node.clone().into();
+            }
+        }
+
+        self.list.inner.grow(1);
+        self.events
+            .push(Event::Inserted(node.clone(), Position::Back));
+
+        Ok(node)
+    }
+
+    pub fn pop_front(&mut self) -> Option<Arc<T>> {
+        let node = self.head.clone()?;
+
+        self.unlink(node, Position::Front)
+    }
+
+    pub fn pop_back(&mut self) -> Option<Arc<T>> {
+        let node = self.tail.clone()?;
+
+        self.unlink(node, Position::Back)
+    }
+
+    pub fn remove(&mut self, node: &Node<T>) -> Option<Arc<T>> {
+        if !self.list.owns(node) {
+            return None;
+        }
+
+        let position = if self.head.as_ref() == Some(node) {
+            Position::Front
+        } else if self.tail.as_ref() == Some(node) {
+            Position::Back
+        } else {
+            Position::Middle
+        };
+
+        self.unlink(node.clone(), position)
+    }
+
+    fn unlink(&mut self, node: Node<T>, position: Position) -> Option<Arc<T>> {
+        let value = detach(&mut self.head, &mut self.tail, &node)?;
+
+        self.list.inner.shrink(1);
+        self.list.inner.recycle(node);
+
+        self.events.push(Event::Removed(value.clone(), position));
+
+        value.into()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Transaction<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transaction")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+pub struct Drain<'a, T> {
+    list: &'a SharedList<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = Arc<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SharedList<T> {
+    type Item = Arc<T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    blocking! {
+        use std::time::Duration;
+    }
+
+    #[test]
+    fn front_back() {
+        let list = SharedList::new();
+
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+
+        assert_eq!(list.front().map(|a| *a), Some(1));
+        assert_eq!(list.back().map(|a| *a), Some(2));
+    }
+
+    #[test]
+    fn push_front() {
+        let list = SharedList::new();
+
+        list.push_front(2).unwrap();
+        list.push_front(1).unwrap();
+
+        assert_eq!(
+            list.head()
+                .unwrap()
+                .into_iter()
+                .map(|a| *a)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn push_back() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+
+        assert_eq!(
+            list.head()
+                .unwrap()
+                .into_iter()
+                .map(|a| *a)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn len() {
+        let list = SharedList::new();
+
+        assert!(list.is_empty());
+
+        let one = list.push_back(1).unwrap();
+        list.push_front(0).unwrap();
+        list.insert_after(&one, 2).unwrap();
+
+        assert_eq!(list.len(), 3);
+
+        list.pop_front();
+        list.remove(&one);
+
+        assert_eq!(list.len(), 1);
+
+        let other = SharedList::from(vec![3, 4, 5]);
+        let four = other.head().unwrap().right().unwrap();
+
+        list.append(&other);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(other.len(), 0);
+
+        assert_eq!(list.split_off(&four).len(), 2);
+        assert_eq!(list.len(), 2);
+
+        list.clear();
+
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let list = SharedList::from(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(list.get(0).map(|a| *a), Some(1));
+        assert_eq!(list.get(1).map(|a| *a), Some(2));
+        assert_eq!(list.get(3).map(|a| *a), Some(4));
+        assert_eq!(list.get(4).map(|a| *a), Some(5));
+        assert_eq!(list.get(5), None);
+
+        assert_eq!(list.get_node(0), list.head());
+        assert_eq!(list.get_node(4), list.tail());
+    }
+
+    #[test]
+    fn contains() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+        assert!(!SharedList::new().contains(&1));
+    }
+
+    #[test]
+    fn find() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        let two = list.push_back(2).unwrap();
+        list.push_back(4).unwrap();
+
+        assert_eq!(list.find(|&a| a % 2 == 0), Some(two));
+        assert_eq!(list.find(|&a| a > 4), None);
+    }
+
+    #[test]
+    fn position() {
+        let list = SharedList::from(vec![1, 2, 4]);
+
+        assert_eq!(list.position(|&a| a % 2 == 0), Some(1));
+        assert_eq!(list.position(|&a| a == 4), Some(2));
+        assert_eq!(list.position(|&a| a > 4), None);
+    }
+
+    #[test]
+    fn insert() {
+        let list = SharedList::new();
+
+        list.insert(0, 2).unwrap();
+        list.insert(1, 5).unwrap();
+        list.insert(0, 1).unwrap();
+        list.insert(2, 4).unwrap();
+        list.insert(2, 3).unwrap();
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4, 5]));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let _ = SharedList::new().insert(1, 1);
+    }
+
+    #[test]
+    fn position_of() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let tail = list.tail().unwrap();
+
+        assert_eq!(list.position_of(&tail), Some(2));
+        assert_eq!(list.position_of(&list.head().unwrap()), Some(0));
+
+        list.pop_front();
+
+        assert_eq!(list.position_of(&tail), Some(1));
+        assert_eq!(SharedList::new().position_of(&tail), None);
+    }
+
+    #[test]
+    fn node_iter() {
+        let list = SharedList::from(vec![1, 2, 3, 4]);
+
+        let nodes = list.node_iter().collect::<Vec<_>>();
+
+        assert_eq!(nodes.first(), list.head().as_ref());
+        assert_eq!(nodes.last(), list.tail().as_ref());
+
+        for node in nodes.iter().step_by(2) {
+            list.remove(node);
+        }
+
+        assert_eq!(list, SharedList::from(vec![2, 4]));
+        assert_eq!(
+            list.node_iter()
+                .rev()
+                .map(|node| *node.value())
+                .collect::<Vec<_>>(),
+            vec![4, 2]
+        );
+    }
+
+    #[test]
+    fn size_hint() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+
+        iter.next();
+        iter.next_back();
+
+        assert_eq!(iter.size_hint(), (0, Some(1)));
+        assert_eq!(list.node_iter().size_hint(), (0, Some(3)));
+        assert_eq!(SharedList::<i32>::new().iter().size_hint(), (0, Some(0)));
+
+        let iter = list.iter();
+
+        list.pop_back();
+        list.pop_back();
+
+        assert_eq!(iter.count(), 1);
+    }
+
+    #[test]
+    fn insert_before() {
+        let list = SharedList::new();
+
+        let two = list.push_back(2).unwrap();
+        let four = list.push_back(4).unwrap();
+
+        list.insert_before(&four, 3).unwrap();
+        list.insert_before(&two, 1).unwrap();
+
+        assert_eq!(*list.head().unwrap().value(), 1);
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn insert_after() {
+        let list = SharedList::new();
+
+        let one = list.push_back(1).unwrap();
+        let three = list.push_back(3).unwrap();
+
+        list.insert_after(&one, 2).unwrap();
+        list.insert_after(&three, 4).unwrap();
+
+        assert_eq!(*list.tail().unwrap().value(), 4);
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn insert_detached() {
+        let a = SharedList::from(vec![1, 2]);
+        let b = SharedList::from(vec![3]);
+
+        let two = a.tail().unwrap();
+
+        assert_eq!(b.insert_before(&two, 0), Err(InsertError::Detached(0)));
+        assert_eq!(b.insert_after(&two, 0), Err(InsertError::Detached(0)));
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.validate(), Ok(()));
+
+        a.pop_back();
+
+        assert_eq!(a.insert_after(&two, 0).unwrap_err().into_inner(), 0);
+        assert_eq!(two.insert_before(0), Err(InsertError::Detached(0)));
+        assert_eq!(a, SharedList::from(vec![1]));
+    }
+
+    #[test]
+    fn node_insert() {
+        let list = SharedList::from(vec![2]);
+
+        let two = list.head().unwrap();
+
+        two.insert_before(1).unwrap();
+        two.insert_after(3).unwrap().insert_after(4).unwrap();
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4]));
+        assert_eq!(*list.head().unwrap().value(), 1);
+        assert_eq!(*list.tail().unwrap().value(), 4);
+        assert!(two.list().is_some_and(|owner| owner.ptr_eq(&list)));
+
+        let other = list.split_off(&two);
+
+        two.insert_after(5).unwrap();
+
+        assert_eq!(other, SharedList::from(vec![2, 5, 3, 4]));
+        assert_eq!(list.len(), 1);
+
+        list.close();
+
+        assert_eq!(
+            list.head().unwrap().insert_after(0),
+            Err(InsertError::Closed(0))
+        );
+    }
+
+    #[test]
+    fn insert_racing_pop() {
+        use std::thread;
+
+        let list = SharedList::from((0..1000).collect::<Vec<_>>());
+
+        thread::scope(|s| {
+            s.spawn(|| while list.pop_back().is_some() {});
+
+            for _ in 0..1000 {
+                if let Some(tail) = list.tail() {
+                    let _ = tail.insert_after(-1);
+                }
+            }
+        });
+
+        assert_eq!(list.validate(), Ok(()));
+        assert_eq!(list.iter().count(), list.len());
+    }
+
+    #[test]
+    fn remove() {
+        let list = SharedList::new();
+
+        let one = list.push_back(1).unwrap();
+        let two = list.push_back(2).unwrap();
+        let three = list.push_back(3).unwrap();
+
+        assert_eq!(*list.remove(&two).unwrap(), 2);
+        assert!(two.is_insulate());
+        assert_eq!(list.remove(&two), None);
+
+        assert_eq!(*list.remove(&one).unwrap(), 1);
+        assert_eq!(list.head(), Some(three.clone()));
+
+        assert_eq!(*list.remove(&three).unwrap(), 3);
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn remove_foreign() {
+        let a = SharedList::from_iter([1, 2, 3]);
+        let b = SharedList::from_iter([10, 20]);
+
+        let mid = a.get_node(1).unwrap();
+
+        assert_eq!(b.remove(&mid), None);
+        assert_eq!(a.validate(), Ok(()));
+        assert_eq!(b.validate(), Ok(()));
+        assert_eq!(
+            b.iter().map(|value| *value).collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+
+        b.append(&a);
+
+        assert_eq!(a.remove(&mid), None);
+        assert_eq!(*b.remove(&mid).unwrap(), 2);
+        assert_eq!(b.validate(), Ok(()));
+        assert_eq!(b.len(), 4);
+    }
+
+    #[test]
+    fn remove_at() {
+        let list = SharedList::from(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(list.remove_at(3).map(|a| *a), Some(4));
+        assert_eq!(list.remove_at(0).map(|a| *a), Some(1));
+        assert_eq!(list.remove_at(2).map(|a| *a), Some(5));
+        assert_eq!(list.remove_at(2), None);
+
+        assert_eq!(list, SharedList::from(vec![2, 3]));
+        assert_eq!(list.len(), 2);
+        assert_eq!(*list.tail().unwrap().value(), 3);
+    }
+
+    #[test]
+    fn for_each_mut() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let head = list.front().unwrap();
+
+        list.for_each_mut(|a| *a *= 10);
+
+        assert_eq!(list, SharedList::from(vec![10, 20, 30]));
+        assert_eq!(*head, 1);
+    }
+
+    #[test]
+    fn display() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(list.to_string(), "[1, 2, 3]");
+        assert_eq!(format!("{:>2}", list), "[ 1,  2,  3]");
+        assert_eq!(list.join(" -> "), "1 -> 2 -> 3");
+        assert_eq!(SharedList::<i32>::new().to_string(), "[]");
+        assert_eq!(SharedList::<i32>::new().join(", "), "");
+    }
+
+    #[test]
+    fn validate() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(list.validate(), Ok(()));
+        assert_eq!(SharedList::<i32>::new().validate(), Ok(()));
+
+        let mid = list.get_node(1).unwrap();
+
+        mid.right().unwrap().set_left(None);
+
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::Asymmetric { index: 1 })
+        );
+
+        mid.right().unwrap().set_left(mid.clone().into());
+        list.inner.len.fetch_add(1, SeqCst);
+
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::LengthMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+
+        list.inner.len.fetch_sub(1, SeqCst);
+        *list.inner.tail.lock().unwrap() = mid.into();
+
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::TailMismatch { index: 2 })
+        );
+    }
+
+    #[test]
+    fn hooks() {
+        use std::sync::Mutex;
+
+        let list = SharedList::new();
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        list.on_insert({
+            let events = Arc::clone(&events);
+
+            move |value: &i32, position| events.lock().unwrap().push(("insert", *value, position))
+        });
+        list.on_remove({
+            let events = Arc::clone(&events);
+
+            move |value: &i32, position| events.lock().unwrap().push(("remove", *value, position))
+        });
+
+        let one = list.push_back(1).unwrap();
+        list.push_front(0).unwrap();
+        list.insert_after(&one, 3).unwrap();
+        list.insert_before(&one, 2).unwrap();
+        list.remove(&one);
+        list.pop_back();
+        list.try_pop_front().unwrap();
+        list.push_back_all([4, 5]).unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                ("insert", 1, Position::Back),
+                ("insert", 0, Position::Front),
+                ("insert", 3, Position::Back),
+                ("insert", 2, Position::Middle),
+                ("remove", 1, Position::Middle),
+                ("remove", 3, Position::Back),
+                ("remove", 0, Position::Front),
+                ("insert", 4, Position::Back),
+                ("insert", 5, Position::Back),
+            ]
+        );
+    }
+
+    #[test]
+    fn push_all() {
+        let list = SharedList::from(vec![3]);
+
+        list.push_back_all(4..=6).unwrap();
+        list.push_front_all([2, 1, 0]).unwrap();
+        list.push_back_all([]).unwrap();
+
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.to_vec(), (0..=6).map(Arc::new).collect::<Vec<_>>());
+        assert_eq!(list.validate(), Ok(()));
+
+        let empty = SharedList::new();
+
+        empty.push_front_all([1, 0]).unwrap();
+
+        assert_eq!(empty.to_vec(), [0, 1].map(Arc::new));
+        assert_eq!(empty.validate(), Ok(()));
+
+        list.close();
+
+        assert_eq!(list.push_back_all([7]).map_err(Closed::into_inner), Err(7));
+    }
+
+    blocking! {
+        #[test]
+        fn push_all_bounded() {
+            use std::thread;
+
+            let list = Arc::new(SharedList::with_capacity_bound(2));
+
+            let consumer = thread::spawn({
+                let list = list.clone();
+
+                move || {
+                    (0..5)
+                        .map(|_| *list.pop_front_blocking().unwrap())
+                        .collect::<Vec<_>>()
+                }
+            });
+
+            list.push_back_all(0..5).unwrap();
+
+            assert_eq!(consumer.join().unwrap(), vec![0, 1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn pop_n() {
+        let list = (0..10).collect::<SharedList<_>>();
+
+        assert_eq!(list.pop_front_n(0), vec![]);
+        assert_eq!(list.pop_front_n(3), [0, 1, 2].map(Arc::new));
+        assert_eq!(list.pop_back_n(2), [9, 8].map(Arc::new));
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.validate(), Ok(()));
+
+        assert_eq!(list.pop_back_n(10), [7, 6, 5, 4, 3].map(Arc::new));
+        assert!(list.is_empty());
+        assert_eq!(list.validate(), Ok(()));
+
+        list.push_back_all(0..3).unwrap();
+
+        assert_eq!(list.pop_front_n(usize::MAX), [0, 1, 2].map(Arc::new));
+        assert_eq!(list.pop_front_n(1), vec![]);
+        assert_eq!(list.pop_back_n(1), vec![]);
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn deep_clone() {
+        let list = SharedList::with_capacity_bound(4);
+
+        list.push_back_all([1, 2, 3]).unwrap();
+
+        let handle = list.handle();
+        let copy = list.deep_clone();
+
+        handle.push_back(4).unwrap();
+        copy.pop_front();
+
+        assert!(handle.ptr_eq(&list));
+        assert!(!copy.ptr_eq(&list));
+        assert_eq!(list.to_vec(), [1, 2, 3, 4].map(Arc::new));
+        assert_eq!(copy.to_vec(), [2, 3].map(Arc::new));
+        assert_eq!(copy.capacity_bound(), Some(4));
+        assert!(Arc::ptr_eq(&copy.front().unwrap(), &list.get(1).unwrap()));
+    }
+
+    #[test]
+    fn owned() {
+        let mut owned = ArenaList::new();
+
+        owned.push_back(1);
+        owned.push_back(2);
+
+        let shared = SharedList::from(owned);
+
+        shared.push_back(3).unwrap();
+
+        let owned = ArenaList::from(shared.handle());
+
+        assert_eq!(shared.len(), 3);
+        assert_eq!(
+            owned.iter().map(|value| **value).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn vecdeque() {
+        let mut deque = VecDeque::from(vec![2, 3]);
+
+        deque.push_front(1);
+
+        let list = SharedList::from(deque);
+
+        list.push_back(4).unwrap();
+
+        assert_eq!(list.into_vecdeque(), VecDeque::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn chunk_by() {
+        let list = SharedList::from(vec![1, 1, 2, 3, 3, 3, 1]);
+
+        let chunks = list
+            .chunk_by(|a, b| a == b)
+            .map(|chunk| chunk.iter().map(|value| **value).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks, vec![vec![1, 1], vec![2], vec![3, 3, 3], vec![1]]);
+
+        let runs = SharedList::from(vec![1, 2, 4, 3, 5])
+            .chunk_by(|a, b| a < b)
+            .map(|chunk| chunk.len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(runs, vec![3, 2]);
+        assert_eq!(SharedList::<i32>::new().chunk_by(|_, _| true).count(), 0);
+    }
+
+    #[test]
+    fn windows() {
+        let list = SharedList::from(vec![1, 2, 3, 4]);
+
+        let sums = list
+            .windows(3)
+            .map(|window| window.iter().map(|value| **value).sum::<i32>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(sums, vec![6, 9]);
+        assert_eq!(list.windows(1).count(), 4);
+        assert_eq!(list.windows(5).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_zero() {
+        SharedList::<i32>::new().windows(0);
+    }
+
+    #[test]
+    fn interleave() {
+        let a = SharedList::from(vec![1, 3, 5, 7, 8]);
+        let b = SharedList::from(vec![2, 4, 6]);
+
+        let list = a.interleave(&b);
+
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.to_vec(), (1..=8).map(Arc::new).collect::<Vec<_>>());
+        assert_eq!(list.validate(), Ok(()));
+
+        let list = b.interleave(&list);
+
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.validate(), Ok(()));
+        assert!(a.interleave(&b).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffle() {
+        use rand::rngs::mock::StepRng;
+
+        let list = (0..100).collect::<SharedList<_>>();
+        let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+
+        list.shuffle(&mut rng);
+
+        let mut values = list.iter().map(|value| *value).collect::<Vec<_>>();
+
+        assert_ne!(values, (0..100).collect::<Vec<_>>());
+        assert_eq!(list.validate(), Ok(()));
+
+        values.sort();
+
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+        assert!(list.choose(&mut rng).is_some_and(|value| *value < 100));
+        assert_eq!(SharedList::<i32>::new().choose(&mut rng), None);
+
+        SharedList::<i32>::new().shuffle(&mut rng);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample() {
+        use rand::rngs::mock::StepRng;
+
+        let list = (0..100).collect::<SharedList<_>>();
+        let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+
+        let mut sample = list
+            .sample(&mut rng, 10)
+            .into_iter()
+            .map(|value| *value)
+            .collect::<Vec<_>>();
+
+        sample.sort();
+        sample.dedup();
+
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|value| *value < 100));
+        assert_eq!(list.sample(&mut rng, 200).len(), 100);
+        assert!(list.sample(&mut rng, 0).is_empty());
+    }
+
+    #[test]
+    fn min_max_node() {
+        let list = SharedList::from(vec![3, -5, 4, 5, -1]);
+
+        let min = list.min_node().unwrap();
+        let max = list.max_node().unwrap();
+
+        assert_eq!(*min.value(), -5);
+        assert_eq!(*max.value(), 5);
+        assert_eq!(
+            *list
+                .min_node_by_key(|value: &i32| value.abs())
+                .unwrap()
+                .value(),
+            -1
+        );
+        assert_eq!(
+            *list
+                .max_node_by_key(|value: &i32| value.abs())
+                .unwrap()
+                .value(),
+            5
+        );
+
+        list.remove(&min);
+        list.move_to_front(&max);
+
+        assert_eq!(list.to_vec(), [5, 3, 4, -1].map(Arc::new));
+        assert_eq!(SharedList::<i32>::new().min_node(), None);
+    }
+
+    #[test]
+    fn for_each_ref() {
+        let list = SharedList::from(vec![1, 2, 3]);
+        let front = list.front().unwrap();
+
+        let mut sum = 0;
+
+        list.for_each_ref(|value| sum += value);
+
+        assert_eq!(sum, 6);
+        assert_eq!(Arc::strong_count(&front), 2);
+
+        let mut visited = 0;
+
+        SharedList::<i32>::new().for_each_ref(|_| visited += 1);
+
+        assert_eq!(visited, 0);
+    }
+
+    #[test]
+    fn for_each_ref_clones() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let nodes = list.node_iter().collect::<Vec<_>>();
+        let values = list.iter().collect::<Vec<_>>();
+
+        let counts = |nodes: &[Node<i32>], values: &[Arc<i32>]| {
+            nodes
+                .iter()
+                .map(|node| Arc::strong_count(&node.routes))
+                .chain(values.iter().map(Arc::strong_count))
+                .collect::<Vec<_>>()
+        };
+
+        let before = counts(&nodes, &values);
+        let mut seen = Vec::new();
+
+        list.for_each_ref(|value| {
+            assert_eq!(counts(&nodes, &values), before);
+
+            seen.push(*value);
+        });
+
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert_eq!(counts(&nodes, &values), before);
+    }
+
+    #[test]
+    fn for_each_ref_concurrent() {
+        use std::thread;
+
+        let list = SharedList::from((0..64).collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for i in 0..10_000 {
+                    list.push_back(i).unwrap();
+                    list.pop_back();
+                }
+            });
+
+            for _ in 0..1_000 {
+                let mut count = 0;
+
+                list.for_each_ref(|_| count += 1);
+
+                assert!((64..=65).contains(&count));
+            }
+        });
+
+        assert_eq!(list.len(), 64);
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn transaction() {
+        use std::{sync::atomic::AtomicUsize, thread};
+
+        let list = Arc::new(SharedList::from(vec![1, 2, 3]));
+        let removed = Arc::new(AtomicUsize::new(0));
+
+        let counter = removed.clone();
+
+        list.on_remove(move |_, _| {
+            counter.fetch_add(1, SeqCst);
+        });
+
+        let middle = list.get_node(1).unwrap();
+
+        let moved = list.transaction(|txn| {
+            let front = txn.pop_front().unwrap();
+
+            txn.remove(&middle);
+            txn.push_back(*front * 10).unwrap();
+            txn.push_front(0).unwrap();
+
+            assert_eq!(txn.len(), 3);
+            assert_eq!(removed.load(SeqCst), 0);
+
+            *front
+        });
+
+        assert_eq!(moved, 1);
+        assert_eq!(removed.load(SeqCst), 2);
+        assert_eq!(list.to_vec(), [0, 3, 10].map(Arc::new));
+        assert_eq!(list.validate(), Ok(()));
+
+        let readers = (0..4)
+            .map(|_| {
+                let list = list.clone();
+
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        let snapshot = list.snapshot();
+
+                        assert_eq!(snapshot.len() % 2, 1);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for i in 0..1000 {
+            list.transaction(|txn| {
+                txn.push_back(i).unwrap();
+                txn.push_back(i).unwrap();
+            });
+        }
+
+        for reader in readers {
+            reader.join().unwrap();
+        }
+
+        assert_eq!(list.len(), 2003);
+    }
+
+    #[test]
+    fn snapshot() {
+        use std::thread;
+
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let snapshot = list.snapshot();
+
+        list.push_back(4).unwrap();
+        list.pop_front();
+
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(*snapshot[0], 1);
+        assert_eq!(
+            snapshot.into_iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let list = SharedList::from((0..100).collect::<Vec<_>>());
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                for value in 100..1000 {
+                    list.push_back(value).unwrap();
+                    list.pop_front();
+                }
+            });
+
+            for _ in 0..100 {
+                let snapshot = list.snapshot();
+
+                assert!((100..=101).contains(&snapshot.len()));
+                assert!(snapshot.windows(2).all(|pair| *pair[1] == *pair[0] + 1));
+            }
+        });
+    }
+
+    #[test]
+    fn map() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let mapped = list.map(|a| a.to_string());
+
+        assert_eq!(
+            mapped,
+            SharedList::from(vec!["1".to_string(), "2".into(), "3".into()])
+        );
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(SharedList::<i32>::new().map(|a| a + 1), SharedList::new());
+    }
+
+    #[test]
+    fn filter() {
+        let list = SharedList::from(vec![1, 2, 3, 4]);
+
+        let head = list.head().unwrap();
+        let even = list.filter(|a| a % 2 == 0);
+
+        assert_eq!(even, SharedList::from(vec![2, 4]));
+        assert_eq!(even.len(), 2);
+        assert_eq!(list.len(), 4);
+        assert!(Arc::ptr_eq(
+            &even.front().unwrap(),
+            &head.right().unwrap().value()
+        ));
+    }
+
+    #[test]
+    fn partition() {
+        let list = SharedList::from(vec![1, 2, 3, 4, 5]);
+
+        let (even, odd) = list.partition(|a| a % 2 == 0);
+
+        assert_eq!(even, SharedList::from(vec![2, 4]));
+        assert_eq!(odd, SharedList::from(vec![1, 3, 5]));
+        assert_eq!(even.len() + odd.len(), list.len());
+
+        let (all, none) = list.partition(|_| true);
+
+        assert_eq!(all, list);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn zip() {
+        let list = SharedList::from(vec![1, 2, 3]);
+        let other = SharedList::from(vec!["a", "b"]);
+
+        let zipped = list.zip(&other);
+
+        assert_eq!(zipped.len(), 2);
+        assert_eq!(
+            zipped
+                .iter()
+                .map(|pair| (*pair.0, *pair.1))
+                .collect::<Vec<_>>(),
+            vec![(1, "a"), (2, "b")]
+        );
+        assert!(list.zip(&SharedList::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn retain() {
+        let list = SharedList::from(vec![1, 2, 3, 4, 5, 6]);
+
+        list.retain(|&a| a % 2 == 0);
+
+        assert_eq!(list, SharedList::from(vec![2, 4, 6]));
+        assert_eq!(list.len(), 3);
+        assert_eq!(*list.head().unwrap().value(), 2);
+        assert_eq!(*list.tail().unwrap().value(), 6);
+
+        list.retain(|_| false);
+
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn pop_back_last() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.pop_back();
+
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+
+        list.push_back(2).unwrap();
+
+        assert_eq!(list, SharedList::from(vec![2]));
+    }
+
+    #[test]
+    fn pop_owned() {
+        let list = SharedList::from(vec![String::from("a"), String::from("b")]);
+        let c = list.push_back(String::from("c")).unwrap();
+
+        assert_eq!(list.pop_front_owned(), Some(Ok(String::from("a"))));
+        assert_eq!(
+            list.pop_back_owned(),
+            Some(Err(Arc::new(String::from("c"))))
+        );
+
+        std::mem::drop(c);
+
+        assert_eq!(list.pop_back_owned(), Some(Ok(String::from("b"))));
+        assert_eq!(list.pop_front_owned(), None);
+    }
+
+    blocking! {
+        #[test]
+        fn pop_blocking() {
+            use std::thread;
+
+            let list = SharedList::new();
+
+            let consumer = thread::spawn({
+                let list = list.clone();
+
+                move || (list.pop_front_blocking(), list.pop_back_blocking())
+            });
+
+            thread::sleep(Duration::from_millis(10));
+
+            list.push_back(1).unwrap();
+            list.append(&SharedList::from(vec![2, 3]));
+
+            assert_eq!(
+                consumer.join().unwrap(),
+                (Some(Arc::new(1)), Some(Arc::new(3)))
+            );
+            assert_eq!(list, SharedList::from(vec![2]));
+        }
+
+        #[test]
+        fn pop_timeout() {
+            use std::thread;
+
+            let list = SharedList::new();
+
+            assert_eq!(list.pop_front_timeout(Duration::from_millis(10)), None);
+            assert_eq!(list.pop_back_timeout(Duration::ZERO), None);
+
+            let producer = thread::spawn({
+                let list = list.clone();
+
+                move || {
+                    thread::sleep(Duration::from_millis(10));
+                    list.push_front(1).unwrap();
+                }
+            });
+
+            assert_eq!(
+                list.pop_back_timeout(Duration::from_secs(10)).map(|a| *a),
+                Some(1)
+            );
+
+            producer.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn try_push() {
+        let list = SharedList::new();
+
+        list.try_push_back(2).unwrap();
+        list.try_push_front(1).unwrap();
+        list.try_push_back(3).unwrap();
+
+        let head = list.inner.head.lock().unwrap();
+
+        assert_eq!(list.try_push_front(0).unwrap_err().into_inner(), 0);
+        assert!(list.try_push_back(4).is_ok());
+
+        std::mem::drop(head);
+
+        let tail = list.tail().unwrap();
+        let guard = tail.routes.lock().unwrap();
+
+        assert!(matches!(
+            list.try_push_back(5),
+            Err(TryPushError::WouldBlock(5))
+        ));
+
+        std::mem::drop(guard);
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4]));
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn try_pop() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        let tail = list.inner.tail.lock().unwrap();
+
+        assert_eq!(list.try_pop_front(), Err(WouldBlock));
+        assert_eq!(list.try_pop_back(), Err(WouldBlock));
+
+        std::mem::drop(tail);
+
+        assert_eq!(list.try_pop_front().unwrap().map(|a| *a), Some(1));
+        assert_eq!(list.try_pop_back().unwrap().map(|a| *a), Some(3));
+        assert_eq!(list.try_pop_back().unwrap().map(|a| *a), Some(2));
+        assert_eq!(list.try_pop_front(), Ok(None));
+        assert_eq!(list.try_pop_back(), Ok(None));
+
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn capacity_bound() {
+        use std::thread;
+
+        let list = SharedList::with_capacity_bound(2);
+
+        assert_eq!(list.capacity_bound(), Some(2));
+        assert_eq!(SharedList::<i32>::new().capacity_bound(), None);
+
+        list.try_push_back(1).unwrap();
+        list.push_back(2).unwrap();
+
+        assert!(matches!(list.try_push_front(0), Err(TryPushError::Full(0))));
+        assert!(matches!(list.try_push_back(3), Err(TryPushError::Full(3))));
+
+        let producer = thread::spawn({
+            let list = list.clone();
+
+            move || {
+                list.push_back(3).unwrap();
+                list.push_front(0).unwrap();
+            }
+        });
+
+        assert_eq!(list.pop_front(), Some(Arc::new(1)));
+        assert_eq!(list.pop_front(), Some(Arc::new(2)));
+
+        producer.join().unwrap();
+
+        assert_eq!(list, SharedList::from(vec![0, 3]));
+
+        list.clear();
+
+        let tail = list.inner.tail.lock().unwrap();
+
+        assert!(matches!(
+            list.try_push_back(1),
+            Err(TryPushError::WouldBlock(1))
+        ));
+
+        std::mem::drop(tail);
+
+        list.try_push_back(1).unwrap();
+        list.try_push_back(2).unwrap();
+
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn move_to_front_back() {
+        let list = SharedList::new();
+
+        let one = list.push_back(1).unwrap();
+        let two = list.push_back(2).unwrap();
+        let three = list.push_back(3).unwrap();
+
+        assert!(list.move_to_front(&two));
+        assert_eq!(list, SharedList::from(vec![2, 1, 3]));
+
+        assert!(list.move_to_front(&three));
+        assert_eq!(list, SharedList::from(vec![3, 2, 1]));
+
+        assert!(list.move_to_front(&three));
+        assert!(list.move_to_back(&three));
+        assert_eq!(list, SharedList::from(vec![2, 1, 3]));
+
+        assert!(list.move_to_back(&two));
+        assert!(list.move_to_back(&one));
+        assert_eq!(list, SharedList::from(vec![3, 2, 1]));
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(list.len(), 3);
+        assert_eq!(*list.head().unwrap().value(), 3);
+        assert_eq!(list.tail().unwrap(), one);
+
+        list.remove(&two);
+
+        assert!(!list.move_to_front(&two));
+        assert!(!list.move_to_back(&two));
+        assert_eq!(list, SharedList::from(vec![3, 1]));
+
+        let single = SharedList::new();
+        let node = single.push_back(1).unwrap();
+
+        assert!(single.move_to_front(&node));
+        assert!(single.move_to_back(&node));
+        assert_eq!(single, SharedList::from(vec![1]));
+    }
+
+    #[test]
+    fn move_foreign() {
+        let a = SharedList::from(vec![1, 2, 3]);
+        let b = SharedList::from(vec![4, 5]);
+
+        let mid = a.get_node(1).unwrap();
+
+        assert!(!b.move_to_front(&mid));
+        assert!(!b.move_to_back(&a.head().unwrap()));
+        assert!(!b.move_to_back(&a.tail().unwrap()));
+        assert_eq!(a, SharedList::from(vec![1, 2, 3]));
+        assert_eq!(b, SharedList::from(vec![4, 5]));
+        assert_eq!(a.validate(), Ok(()));
+        assert_eq!(b.validate(), Ok(()));
+
+        assert!(a.move_to_back(&mid));
+        assert_eq!(*a.remove(&mid).unwrap(), 2);
+    }
+
+    #[test]
+    fn swap() {
+        let list = SharedList::new();
+
+        let nodes = (0..5)
+            .map(|value| list.push_back(value).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(list.swap(&nodes[0], &nodes[4]));
+        assert_eq!(list, SharedList::from(vec![4, 1, 2, 3, 0]));
+
+        assert!(list.swap(&nodes[1], &nodes[2]));
+        assert_eq!(list, SharedList::from(vec![4, 2, 1, 3, 0]));
+
+        assert!(list.swap(&nodes[0], &nodes[3]));
+        assert_eq!(list, SharedList::from(vec![4, 2, 1, 0, 3]));
+
+        assert!(list.swap(&nodes[2], &nodes[3]));
+        assert_eq!(list, SharedList::from(vec![4, 3, 1, 0, 2]));
+
+        assert!(list.swap(&nodes[1], &nodes[1]));
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![2, 0, 1, 3, 4]
+        );
+        assert_eq!(list.head().unwrap(), nodes[4]);
+        assert_eq!(list.tail().unwrap(), nodes[2]);
+
+        list.remove(&nodes[0]);
+
+        assert!(!list.swap(&nodes[0], &nodes[1]));
+        assert_eq!(list, SharedList::from(vec![4, 3, 1, 2]));
+
+        let pair = SharedList::new();
+        let a = pair.push_back('a').unwrap();
+        let b = pair.push_back('b').unwrap();
+
+        assert!(pair.swap(&b, &a));
+        assert_eq!(pair, SharedList::from(vec!['b', 'a']));
+        assert_eq!(pair.head().unwrap(), b);
+        assert_eq!(pair.tail().unwrap(), a);
+    }
+
+    #[test]
+    fn swap_foreign() {
+        let a = SharedList::from(vec![1, 2, 3]);
+        let b = SharedList::from(vec![4, 5]);
+
+        let mid = a.get_node(1).unwrap();
+        let head = b.head().unwrap();
+
+        assert!(!b.swap(&head, &mid));
+        assert!(!b.swap(&mid, &mid));
+        assert!(!a.swap(&head, &mid));
+        assert_eq!(a, SharedList::from(vec![1, 2, 3]));
+        assert_eq!(b, SharedList::from(vec![4, 5]));
+        assert_eq!(a.validate(), Ok(()));
+        assert_eq!(b.validate(), Ok(()));
+
+        assert!(a.swap(&mid, &a.head().unwrap()));
+        assert_eq!(a, SharedList::from(vec![2, 1, 3]));
+        assert_eq!(*a.remove(&mid).unwrap(), 2);
+    }
+
+    #[test]
+    fn recycle() {
+        let list = SharedList::new();
+
+        let routes = Arc::as_ptr(&list.push_back(1).unwrap().routes);
+        let one = list.pop_front().unwrap();
+
+        assert_eq!(*one, 1);
+        assert_eq!(Arc::strong_count(&one), 1);
+
+        let node = list.push_front(2).unwrap();
+
+        assert_eq!(Arc::as_ptr(&node.routes), routes);
+
+        list.push_back(3).unwrap();
+
+        assert_eq!(list.pop_front().map(|a| *a), Some(2));
+        assert!(node.is_insulate());
+
+        let other = list.push_back(4).unwrap();
+
+        assert_ne!(Arc::as_ptr(&other.routes), routes);
+        assert_eq!(list.pop_back().map(|a| *a), Some(4));
 
-    pub fn tail(&self) -> Option<Node<T>> {
-        self.tail.lock().unwrap().clone()
+        std::mem::drop(other);
+
+        assert_eq!(list.iter().map(|a| *a).collect::<Vec<_>>(), vec![3]);
+        assert_eq!(list.inner.free.lock().unwrap().len(), 0);
     }
 
-    pub fn push_front(&self, value: T) -> Node<T> {
-        loop {
-            let mut head = self.head.lock().unwrap();
+    #[test]
+    fn node_pool() {
+        let list = SharedList::with_node_pool(2);
 
-            if let Some(head) = head.as_mut() {
-                *head = head.insert_left(value);
+        let pooled = list
+            .inner
+            .free
+            .lock()
+            .unwrap()
+            .iter()
+            .map(Arc::as_ptr)
+            .collect::<Vec<_>>();
 
-                break head.clone();
-            } else {
-                let mut tail = try_lock!(self.tail);
+        assert_eq!(pooled.len(), 2);
 
-                let node = Node::new_insulated(value);
+        let nodes = (1..=3)
+            .map(|value| list.push_back(value).unwrap())
+            .collect::<Vec<_>>();
 
-                *head = node.clone().into();
-                *tail = node.clone().into();
+        assert!(pooled.contains(&Arc::as_ptr(&nodes[0].routes)));
+        assert!(pooled.contains(&Arc::as_ptr(&nodes[1].routes)));
+        assert!(!pooled.contains(&Arc::as_ptr(&nodes[2].routes)));
+        assert_eq!(list.inner.free.lock().unwrap().len(), 0);
 
-                break node;
-            }
-        }
+        std::mem::drop(nodes);
+
+        while list.pop_front().is_some() {}
+
+        assert_eq!(list.inner.free.lock().unwrap().len(), 2);
+        assert_eq!(list.validate(), Ok(()));
     }
 
-    pub fn push_back(&self, value: T) -> Node<T> {
-        let mut tail = self.tail.lock().unwrap();
+    #[test]
+    fn poisoned() {
+        use std::thread;
+
+        let list = SharedList::new();
 
-        if let Some(tail) = tail.as_mut() {
-            *tail = tail.insert_right(value);
+        list.push_back(1).unwrap();
 
-            tail.clone()
-        } else {
-            let mut head = self.head.lock().unwrap();
+        let head = list.head().unwrap();
 
-            let node = Node::new_insulated(value);
+        thread::scope(|scope| {
+            let poison = scope.spawn(|| {
+                let _tail = list.inner.tail.lock().unwrap();
+                let _routes = head.routes.lock().unwrap();
+                let _waiting = list.inner.waiting.lock().unwrap();
 
-            *head = node.clone().into();
-            *tail = node.clone().into();
+                panic!("poison");
+            });
 
-            node
-        }
+            assert!(poison.join().is_err());
+        });
+
+        list.push_back(2).unwrap();
+        list.push_front(0).unwrap();
+
+        assert_eq!(list.try_pop_back(), Ok(Some(Arc::new(2))));
+        assert_eq!(list.pop_front().map(|a| *a), Some(0));
+        assert_eq!(list.iter().map(|a| *a).collect::<Vec<_>>(), vec![1]);
     }
 
-    pub fn pop_front(&self) -> Option<Arc<T>> {
-        let mut tail = self.tail.lock().unwrap();
-        let mut head = self.head.lock().unwrap();
+    blocking! {
+        #[test]
+        fn close() {
+            use std::thread;
 
-        if *tail == *head {
-            if let Some(head) = head.take() {
-                tail.take();
+            let list = SharedList::with_capacity_bound(2);
 
-                head.value.into()
-            } else {
-                None
-            }
-        } else {
-            drop(tail);
+            list.push_back(1).unwrap();
+            list.push_back(2).unwrap();
 
-            let head = unsafe { head.as_mut().unwrap_unchecked() };
+            let producer = thread::spawn({
+                let list = list.clone();
 
-            let (value, right) = head.insulate_right_owned();
+                move || list.push_back(3)
+            });
 
-            *head = unsafe { right.unwrap_unchecked() };
+            thread::sleep(Duration::from_millis(10));
 
-            value.into()
-        }
-    }
+            list.close();
 
-    pub fn pop_back(&self) -> Option<Arc<T>> {
-        let mut tail = self.tail.lock().unwrap();
+            assert!(list.is_closed());
+            assert_eq!(producer.join().unwrap().unwrap_err().into_inner(), 3);
+            assert_eq!(list.push_front(0).unwrap_err(), Closed(0));
+            assert!(matches!(
+                list.try_push_back(4),
+                Err(TryPushError::Closed(4))
+            ));
 
-        if let Some(tail) = tail.as_mut() {
-            let (value, left) = tail.insulate_left_owned();
+            assert_eq!(list.pop_front_blocking().map(|a| *a), Some(1));
+            assert_eq!(list.pop_back_blocking().map(|a| *a), Some(2));
+            assert_eq!(list.pop_front_blocking(), None);
+            assert_eq!(list.pop_back_timeout(Duration::from_secs(10)), None);
 
-            if let Some(left) = left {
-                *tail = left;
-            } else {
-                self.head.lock().unwrap().take();
-            }
+            let list = SharedList::<i32>::new();
 
-            value.into()
-        } else {
-            None
-        }
-    }
-}
+            let consumer = thread::spawn({
+                let list = list.clone();
 
-impl<T> Clone for LinkedList<T> {
-    fn clone(&self) -> Self {
-        Self {
-            head: Arc::clone(&self.head),
-            tail: Arc::clone(&self.tail),
+                move || list.pop_front_blocking()
+            });
+
+            thread::sleep(Duration::from_millis(10));
+
+            list.close();
+
+            assert_eq!(consumer.join().unwrap(), None);
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn drain() {
+        let list = SharedList::from(vec![1, 2, 3, 4]);
+
+        let mut drain = list.drain();
+
+        assert_eq!(drain.next().map(|a| *a), Some(1));
+        assert_eq!(drain.next_back().map(|a| *a), Some(4));
+        assert_eq!(list.len(), 2);
+        assert_eq!(drain.map(|a| *a).collect::<Vec<_>>(), vec![2, 3]);
+
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
 
     #[test]
-    fn push_front() {
-        let list = LinkedList::new();
+    fn sort() {
+        let list = SharedList::from(vec![5, 1, 4, 2, 3, 2]);
+        let one = list.get_node(1).unwrap();
 
-        list.push_front(2);
-        list.push_front(1);
+        list.sort();
 
+        assert_eq!(list, SharedList::from(vec![1, 2, 2, 3, 4, 5]));
+        assert_eq!(list.head(), Some(one));
+        assert_eq!(*list.tail().unwrap().value(), 5);
         assert_eq!(
-            list.head()
-                .unwrap()
-                .into_iter()
-                .map(|a| *a)
-                .collect::<Vec<_>>(),
-            vec![1, 2]
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 2, 1]
         );
+
+        let empty = SharedList::<i32>::new();
+
+        empty.sort();
+
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn push_back() {
-        let list = LinkedList::new();
+    fn sort_by() {
+        let list = SharedList::from(vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (2, 'e')]);
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(list.iter().map(|a| a.1).collect::<String>(), "bdace");
+
+        let list = (0..100).map(|a| (a * 37) % 100).collect::<SharedList<_>>();
+
+        list.sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(list, (0..100).rev().collect());
+    }
+
+    #[test]
+    fn sort_by_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let list = SharedList::from(vec![5, 3, 1, 4, 2]);
+        let mut calls = 0;
+
+        let sorted = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                assert_eq!(list.front(), None);
+
+                calls += 1;
+
+                if calls == 4 {
+                    panic!("compare");
+                }
+
+                a.cmp(b)
+            })
+        }));
+
+        assert!(sorted.is_err());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.validate(), Ok(()));
+
+        let mut values = list.iter().map(|a| *a).collect::<Vec<_>>();
+
+        values.sort();
+
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+
+        list.sort();
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn merge() {
+        let list = SharedList::from(vec![1, 3, 5, 7]);
+        let other = SharedList::from(vec![2, 3, 4, 8, 9]);
+        let three = list.get_node(1).unwrap();
+
+        list.merge(&other);
 
-        list.push_back(1);
-        list.push_back(2);
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 3, 4, 5, 7, 8, 9]));
+        assert_eq!(list.get_node(2), Some(three));
+        assert_eq!(*list.tail().unwrap().value(), 9);
+        assert_eq!(list.len(), 9);
+        assert!(other.is_empty());
+        assert_eq!(other.head(), None);
 
+        let empty = SharedList::new();
+
+        empty.merge(&list);
+
+        assert_eq!(empty.len(), 9);
         assert_eq!(
-            list.head()
-                .unwrap()
-                .into_iter()
-                .map(|a| *a)
-                .collect::<Vec<_>>(),
-            vec![1, 2]
+            empty.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![9, 8, 7, 5, 4, 3, 3, 2, 1]
         );
     }
 
+    #[test]
+    fn merge_by() {
+        let list = SharedList::from(vec![5, 3, 1]);
+
+        list.merge_by(&SharedList::from(vec![6, 4, 2]), |a, b| b.cmp(a));
+
+        assert_eq!(list, SharedList::from(vec![6, 5, 4, 3, 2, 1]));
+    }
+
     #[test]
     fn pop_front() {
-        let list = LinkedList::new();
+        let list = SharedList::new();
 
-        list.push_back(1);
-        list.push_back(2);
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
 
         assert_eq!(*list.pop_front().unwrap(), 1);
 
@@ -175,10 +4410,10 @@ mod tests {
 
     #[test]
     fn pop_back() {
-        let list = LinkedList::new();
+        let list = SharedList::new();
 
-        list.push_back(1);
-        list.push_back(2);
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
 
         assert_eq!(*list.pop_back().unwrap(), 2);
 
@@ -191,4 +4426,380 @@ mod tests {
             vec![1]
         );
     }
+
+    #[test]
+    fn iter() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+        list.push_back(3).unwrap();
+
+        assert_eq!(list.iter().map(|a| *a).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((&list).into_iter().count(), 3);
+        assert_eq!(SharedList::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_back(2).unwrap();
+        list.push_back(3).unwrap();
+
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next().map(|a| *a), Some(1));
+        assert_eq!(iter.next_back().map(|a| *a), Some(3));
+        assert_eq!(iter.next().map(|a| *a), Some(2));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn from_iter() {
+        let list = (1..=3).collect::<SharedList<_>>();
+
+        assert_eq!(list.iter().map(|a| *a).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let shared = Arc::new(4);
+        let list = vec![Arc::clone(&shared)]
+            .into_iter()
+            .collect::<SharedList<_>>();
+
+        assert!(Arc::ptr_eq(&list.head().unwrap().value(), &shared));
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.extend(vec![2, 3]);
+
+        let shared = list.clone();
+
+        shared.extend_from_iter(4..=5).unwrap();
+
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn vec_conversions() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(list.to_vec(), vec![Arc::new(1), Arc::new(2), Arc::new(3)]);
+        assert_eq!(list.clone().into_vec(), vec![1, 2, 3]);
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn debug() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", SharedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn eq() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3]));
+        assert_ne!(list, SharedList::from(vec![1, 2]));
+        assert_ne!(list, SharedList::from(vec![1, 2, 4]));
+        assert_eq!(SharedList::<i32>::new(), SharedList::new());
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+
+        set.insert(SharedList::from(vec![1, 2, 3]));
+        set.insert(SharedList::from(vec![1, 2]));
+
+        assert!(set.contains(&SharedList::from(vec![1, 2, 3])));
+        assert!(set.contains(&SharedList::from(vec![1, 2])));
+        assert!(!set.contains(&SharedList::from(vec![1])));
+    }
+
+    #[test]
+    fn ord() {
+        let mut lists = vec![
+            SharedList::from(vec![2]),
+            SharedList::from(vec![1, 2, 3]),
+            SharedList::new(),
+            SharedList::from(vec![1, 2]),
+        ];
+
+        lists.sort();
+
+        assert_eq!(
+            lists,
+            vec![
+                SharedList::new(),
+                SharedList::from(vec![1, 2]),
+                SharedList::from(vec![1, 2, 3]),
+                SharedList::from(vec![2]),
+            ]
+        );
+        assert_eq!(
+            SharedList::from(vec![1.0]).partial_cmp(&SharedList::from(vec![f64::NAN])),
+            None
+        );
+    }
+
+    #[test]
+    fn append() {
+        let list = SharedList::from(vec![1, 2]);
+        let other = SharedList::from(vec![3, 4]);
+
+        list.append(&other);
+        list.append(&SharedList::new());
+        list.append(&list.clone());
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4]));
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        assert_eq!(other.head(), None);
+        assert_eq!(other.tail(), None);
+
+        let empty = SharedList::new();
+
+        empty.append(&list);
+
+        assert_eq!(empty, SharedList::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn concat() {
+        let first = SharedList::from(vec![1, 2]);
+        let tail = first.tail().unwrap();
+
+        let list = SharedList::concat([
+            first,
+            SharedList::new(),
+            SharedList::from(vec![3]),
+            SharedList::from(vec![4, 5]),
+        ]);
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4, 5]));
+        assert_eq!(list.len(), 5);
+        assert_eq!(tail.right().map(|node| *node.value()), Some(3));
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert!(SharedList::<i32>::concat([]).is_empty());
+    }
+
+    #[test]
+    fn splice() {
+        let list = SharedList::from(vec![1, 5]);
+        let other = SharedList::from(vec![0, 2, 3, 4, 6]);
+
+        let at = list.tail().unwrap();
+        let first = other.get_node(1).unwrap();
+        let last = other.get_node(3).unwrap();
+
+        assert!(!list.splice(&at, &other, &last, &first));
+        assert!(!list.splice(&at, &list, &at, &at));
+        assert!(list.splice(&at, &other, &first, &last));
+        assert!(!list.splice(&at, &other, &first, &last));
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3, 4, 5]));
+        assert_eq!(other, SharedList::from(vec![0, 6]));
+        assert_eq!(list.len(), 5);
+        assert_eq!(other.len(), 2);
+        assert_eq!(
+            list.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            other.iter().rev().map(|a| *a).collect::<Vec<_>>(),
+            vec![6, 0]
+        );
+
+        let head = list.head().unwrap();
+        let first = other.head().unwrap();
+        let last = other.tail().unwrap();
+
+        assert!(list.splice(&head, &other, &first, &last));
+        assert_eq!(list, SharedList::from(vec![0, 6, 1, 2, 3, 4, 5]));
+        assert_eq!(list.head(), Some(first));
+        assert_eq!(other.head(), None);
+        assert_eq!(other.tail(), None);
+        assert!(other.is_empty());
+
+        let other = SharedList::from(vec![7, 8, 9]);
+        let eight = other.get_node(1).unwrap();
+
+        list.remove(&head);
+
+        assert!(!list.splice(&head, &other, &eight, &eight));
+        assert_eq!(other, SharedList::from(vec![7, 8, 9]));
+        assert_eq!(other.len(), 3);
+        assert_eq!(other.validate(), Ok(()));
+        assert_eq!(list, SharedList::from(vec![0, 6, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn split_at() {
+        let (left, right) = SharedList::from(vec![1, 2, 3, 4, 5]).split_at(3);
+
+        assert_eq!(left, SharedList::from(vec![1, 2, 3]));
+        assert_eq!(right, SharedList::from(vec![4, 5]));
+        assert_eq!(left.len(), 3);
+        assert_eq!(right.len(), 2);
+        assert_eq!(left.tail().unwrap().right(), None);
+        assert_eq!(right.head().unwrap().left(), None);
+
+        let (left, right) = left.split_at(0);
+
+        assert!(left.is_empty());
+        assert_eq!(right.len(), 3);
+
+        let (left, right) = right.split_at(3);
+
+        assert_eq!(left.len(), 3);
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        SharedList::from(vec![1]).split_at(2);
+    }
+
+    #[test]
+    fn truncate() {
+        let list = SharedList::from(vec![1, 2, 3, 4]);
+
+        let dropped = list.tail().unwrap();
+
+        list.truncate(5);
+        list.truncate(2);
+
+        assert_eq!(list, SharedList::from(vec![1, 2]));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.tail().map(|node| *node.value()), Some(2));
+        assert_eq!(list.tail().unwrap().right(), None);
+        assert!(dropped.is_insulate());
+
+        list.truncate(0);
+
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn split_off() {
+        let list = SharedList::new();
+
+        let one = list.push_back(1).unwrap();
+        let two = list.push_back(2).unwrap();
+        list.push_back(3).unwrap();
+
+        let other = list.split_off(&two);
+
+        assert_eq!(list, SharedList::from(vec![1]));
+        assert_eq!(other, SharedList::from(vec![2, 3]));
+        assert_eq!(list.tail(), Some(one.clone()));
+        assert_eq!(other.head(), Some(two.clone()));
+
+        assert_eq!(list.split_off(&two), SharedList::new());
+
+        let rest = list.split_off(&one);
+
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+        assert_eq!(rest, SharedList::from(vec![1]));
+    }
+
+    #[test]
+    fn split_off_foreign() {
+        let c = SharedList::from(vec![1, 2]);
+        let d = SharedList::from(vec![3, 4, 5]);
+
+        assert_eq!(c.split_off(&d.tail().unwrap()), SharedList::new());
+        assert_eq!(c.validate(), Ok(()));
+        assert_eq!(d.validate(), Ok(()));
+
+        let two = c.tail().unwrap();
+
+        c.remove(&two);
+
+        assert_eq!(c.split_off(&two), SharedList::new());
+        assert_eq!(c, SharedList::from(vec![1]));
+
+        let four = d.get_node(1).unwrap();
+        let rest = d.split_off(&four);
+
+        assert_eq!(d.remove(&four), None);
+        assert_eq!(*rest.remove(&four).unwrap(), 4);
+        assert_eq!(rest, SharedList::from(vec![5]));
+    }
+
+    #[test]
+    fn clear() {
+        let list = SharedList::from(vec![1, 2]);
+        let node = list.push_back(3).unwrap();
+
+        list.clear();
+
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+        assert!(node.is_insulate());
+        assert_eq!(*node.value(), 3);
+
+        list.push_back(4).unwrap();
+
+        assert_eq!(list, SharedList::from(vec![4]));
+    }
+
+    #[test]
+    fn drop() {
+        let list = SharedList::from(vec![1, 2, 3]);
+        let node = list.push_back(4).unwrap();
+        let value = Arc::downgrade(&list.tail().unwrap().value());
+        let handle = list.clone();
+
+        std::mem::drop(list);
+
+        assert!(value.upgrade().is_some());
+        assert!(!node.is_insulate());
+
+        std::mem::drop(handle);
+
+        assert!(node.is_insulate());
+
+        std::mem::drop(node);
+
+        assert!(value.upgrade().is_none());
+    }
+
+    #[test]
+    fn drop_long() {
+        let list = (0..1_000_000).collect::<SharedList<_>>();
+        let node = list.push_back(1_000_000).unwrap();
+
+        std::mem::drop(list);
+
+        assert!(node.is_insulate());
+    }
 }
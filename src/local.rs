@@ -0,0 +1,450 @@
+use core::{cell::RefCell, fmt, mem, ptr};
+
+use alloc::rc::{Rc, Weak};
+
+struct LocalRoutes<T> {
+    value: Rc<T>,
+    left: Option<LocalNode<T>>,
+    right: Option<LocalNode<T>>,
+    owner: Weak<RefCell<Ends<T>>>,
+}
+
+pub struct LocalNode<T> {
+    routes: Rc<RefCell<LocalRoutes<T>>>,
+}
+
+impl<T> LocalNode<T> {
+    fn new(value: T, owner: Weak<RefCell<Ends<T>>>) -> Self {
+        Self {
+            routes: Rc::new(RefCell::new(LocalRoutes {
+                value: Rc::new(value),
+                left: None,
+                right: None,
+                owner,
+            })),
+        }
+    }
+
+    pub fn value(&self) -> Rc<T> {
+        Rc::clone(&self.routes.borrow().value)
+    }
+
+    pub fn replace(&self, value: T) -> Rc<T> {
+        mem::replace(&mut self.routes.borrow_mut().value, Rc::new(value))
+    }
+
+    pub fn set(&self, value: T) {
+        self.replace(value);
+    }
+
+    pub fn left(&self) -> Option<LocalNode<T>> {
+        self.routes.borrow().left.clone()
+    }
+
+    pub fn right(&self) -> Option<LocalNode<T>> {
+        self.routes.borrow().right.clone()
+    }
+
+    pub fn is_insulate(&self) -> bool {
+        let routes = self.routes.borrow();
+
+        routes.left.is_none() && routes.right.is_none()
+    }
+
+    fn set_left(&self, left: Option<LocalNode<T>>) {
+        self.routes.borrow_mut().left = left;
+    }
+
+    fn set_right(&self, right: Option<LocalNode<T>>) {
+        self.routes.borrow_mut().right = right;
+    }
+
+    fn is_owned_by(&self, ends: &Rc<RefCell<Ends<T>>>) -> bool {
+        ptr::eq(self.routes.borrow().owner.as_ptr(), Rc::as_ptr(ends))
+    }
+}
+
+impl<T> Clone for LocalNode<T> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: Rc::clone(&self.routes),
+        }
+    }
+}
+
+impl<T> PartialEq for LocalNode<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.routes, &other.routes)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LocalNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let routes = self.routes.borrow();
+
+        f.debug_struct("LocalNode")
+            .field("value", &routes.value)
+            .field("left", &routes.left.is_some())
+            .field("right", &routes.right.is_some())
+            .finish()
+    }
+}
+
+struct Ends<T> {
+    head: Option<LocalNode<T>>,
+    tail: Option<LocalNode<T>>,
+    len: usize,
+}
+
+impl<T> Drop for Ends<T> {
+    fn drop(&mut self) {
+        self.tail.take();
+
+        let mut next = self.head.take();
+
+        while let Some(node) = next {
+            let mut routes = node.routes.borrow_mut();
+
+            routes.left = None;
+            routes.owner = Weak::new();
+            next = routes.right.take();
+        }
+    }
+}
+
+pub struct LocalList<T> {
+    ends: Rc<RefCell<Ends<T>>>,
+}
+
+impl<T> LocalList<T> {
+    pub fn new() -> Self {
+        Self {
+            ends: Rc::new(RefCell::new(Ends {
+                head: None,
+                tail: None,
+                len: 0,
+            })),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ends.borrow().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn head(&self) -> Option<LocalNode<T>> {
+        self.ends.borrow().head.clone()
+    }
+
+    pub fn tail(&self) -> Option<LocalNode<T>> {
+        self.ends.borrow().tail.clone()
+    }
+
+    pub fn front(&self) -> Option<Rc<T>> {
+        self.head().map(|node| node.value())
+    }
+
+    pub fn back(&self) -> Option<Rc<T>> {
+        self.tail().map(|node| node.value())
+    }
+
+    pub fn iter(&self) -> LocalIter<T> {
+        LocalIter {
+            next: self.head(),
+            len: self.len(),
+        }
+    }
+
+    pub fn push_front(&self, value: T) -> LocalNode<T> {
+        let mut ends = self.ends.borrow_mut();
+
+        let node = LocalNode::new(value, Rc::downgrade(&self.ends));
+
+        match ends.head.replace(node.clone()) {
+            Some(head) => {
+                head.set_left(node.clone().into());
+                node.set_right(head.into());
+            }
+            None => ends.tail = node.clone().into(),
+        }
+
+        ends.len += 1;
+
+        node
+    }
+
+    pub fn push_back(&self, value: T) -> LocalNode<T> {
+        let mut ends = self.ends.borrow_mut();
+
+        let node = LocalNode::new(value, Rc::downgrade(&self.ends));
+
+        match ends.tail.replace(node.clone()) {
+            Some(tail) => {
+                tail.set_right(node.clone().into());
+                node.set_left(tail.into());
+            }
+            None => ends.head = node.clone().into(),
+        }
+
+        ends.len += 1;
+
+        node
+    }
+
+    pub fn insert_before(&self, node: &LocalNode<T>, value: T) -> Option<LocalNode<T>> {
+        if !node.is_owned_by(&self.ends) {
+            return None;
+        }
+
+        let Some(left) = node.left() else {
+            return self.push_front(value).into();
+        };
+
+        let mid = LocalNode::new(value, Rc::downgrade(&self.ends));
+
+        mid.set_left(left.clone().into());
+        mid.set_right(node.clone().into());
+        left.set_right(mid.clone().into());
+        node.set_left(mid.clone().into());
+
+        self.ends.borrow_mut().len += 1;
+
+        mid.into()
+    }
+
+    pub fn insert_after(&self, node: &LocalNode<T>, value: T) -> Option<LocalNode<T>> {
+        if !node.is_owned_by(&self.ends) {
+            return None;
+        }
+
+        let Some(right) = node.right() else {
+            return self.push_back(value).into();
+        };
+
+        let mid = LocalNode::new(value, Rc::downgrade(&self.ends));
+
+        mid.set_left(node.clone().into());
+        mid.set_right(right.clone().into());
+        node.set_right(mid.clone().into());
+        right.set_left(mid.clone().into());
+
+        self.ends.borrow_mut().len += 1;
+
+        mid.into()
+    }
+
+    pub fn remove(&self, node: &LocalNode<T>) -> Option<Rc<T>> {
+        if !node.is_owned_by(&self.ends) {
+            return None;
+        }
+
+        let mut ends = self.ends.borrow_mut();
+
+        let (left, right) = {
+            let mut routes = node.routes.borrow_mut();
+
+            routes.owner = Weak::new();
+
+            (routes.left.take(), routes.right.take())
+        };
+
+        match left.as_ref() {
+            Some(left) => left.set_right(right.clone()),
+            None => ends.head = right.clone(),
+        }
+
+        match right {
+            Some(right) => right.set_left(left),
+            None => ends.tail = left,
+        }
+
+        ends.len -= 1;
+
+        node.value().into()
+    }
+
+    pub fn pop_front(&self) -> Option<Rc<T>> {
+        self.remove(&self.head()?)
+    }
+
+    pub fn pop_back(&self) -> Option<Rc<T>> {
+        self.remove(&self.tail()?)
+    }
+
+    pub fn clear(&self) {
+        *self.ends.borrow_mut() = Ends {
+            head: None,
+            tail: None,
+            len: 0,
+        };
+    }
+}
+
+impl<T> Default for LocalList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for LocalList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ends: Rc::clone(&self.ends),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LocalList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LocalList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> FromIterator<T> for LocalList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let list = Self::new();
+
+        for value in iter {
+            list.push_back(value);
+        }
+
+        list
+    }
+}
+
+pub struct LocalIter<T> {
+    next: Option<LocalNode<T>>,
+    len: usize,
+}
+
+impl<T> Iterator for LocalIter<T> {
+    type Item = Rc<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take().filter(|_| self.len > 0)?;
+
+        self.next = node.right();
+        self.len -= 1;
+
+        node.value().into()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len))
+    }
+}
+
+impl<T> IntoIterator for &LocalList<T> {
+    type Item = Rc<T>;
+    type IntoIter = LocalIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(list: &LocalList<i32>) -> Vec<i32> {
+        list.iter().map(|value| *value).collect()
+    }
+
+    #[test]
+    fn push_pop() {
+        let list = LocalList::new();
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front().as_deref(), Some(&1));
+        assert_eq!(list.back().as_deref(), Some(&3));
+
+        assert_eq!(list.pop_front().as_deref(), Some(&1));
+        assert_eq!(list.pop_back().as_deref(), Some(&3));
+        assert_eq!(list.pop_back().as_deref(), Some(&2));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn insert_remove() {
+        let list = [1, 3].into_iter().collect::<LocalList<_>>();
+
+        let three = list.tail().unwrap();
+        let two = list.insert_before(&three, 2).unwrap();
+
+        list.insert_after(&three, 4).unwrap();
+        list.insert_before(&list.head().unwrap(), 0).unwrap();
+
+        assert_eq!(values(&list), vec![0, 1, 2, 3, 4]);
+
+        assert_eq!(list.remove(&two).as_deref(), Some(&2));
+        assert_eq!(list.remove(&two), None);
+        assert!(list.insert_before(&two, 9).is_none());
+        assert!(list.insert_after(&two, 9).is_none());
+        assert_eq!(three.left().map(|node| *node.value()), Some(1));
+
+        three.set(5);
+
+        assert_eq!(values(&list), vec![0, 1, 5, 4]);
+        assert_eq!(list.len(), 4);
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert!(three.is_insulate());
+    }
+
+    #[test]
+    fn foreign() {
+        let a = [1, 2, 3].into_iter().collect::<LocalList<_>>();
+        let b = [4].into_iter().collect::<LocalList<_>>();
+
+        let two = a.head().unwrap().right().unwrap();
+
+        assert_eq!(b.remove(&two), None);
+        assert!(b.insert_before(&two, 0).is_none());
+        assert!(b.insert_after(&two, 0).is_none());
+        assert_eq!(values(&a), vec![1, 2, 3]);
+        assert_eq!(values(&b), vec![4]);
+        assert_eq!(b.len(), 1);
+
+        let one = a.head().unwrap();
+
+        a.clear();
+
+        assert!(a.insert_after(&one, 0).is_none());
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn handles() {
+        let list = LocalList::new();
+        let handle = list.clone();
+
+        handle.push_back(1);
+
+        assert_eq!(list, [1].into_iter().collect());
+        assert_eq!(format!("{list:?}"), "[1]");
+    }
+
+    #[test]
+    fn drop_long() {
+        let list = (0..100_000).collect::<LocalList<_>>();
+
+        assert_eq!(list.len(), 100_000);
+    }
+}
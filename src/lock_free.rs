@@ -0,0 +1,250 @@
+use std::{
+    fmt,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering::{Acquire, Relaxed, Release},
+        },
+        Arc,
+    },
+};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned, Shared};
+
+struct LockFreeCell<T> {
+    value: MaybeUninit<Arc<T>>,
+    right: Atomic<LockFreeCell<T>>,
+}
+
+/// A FIFO queue of `Arc<T>` for the `lock-free` feature.
+///
+/// Only `push_back` and `pop_front` are offered, and values have no node handles.
+/// Use `SharedList` when you need both ends or positional operations.
+pub struct LockFreeQueue<T> {
+    head: Atomic<LockFreeCell<T>>,
+    tail: Atomic<LockFreeCell<T>>,
+    len: AtomicUsize,
+}
+
+impl<T> LockFreeQueue<T> {
+    pub fn new() -> Self {
+        let sentinel = Owned::new(LockFreeCell {
+            value: MaybeUninit::uninit(),
+            right: Atomic::null(),
+        })
+        .into_shared(unsafe { epoch::unprotected() });
+
+        Self {
+            head: Atomic::from(sentinel),
+            tail: Atomic::from(sentinel),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.load(Relaxed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn push_back(&self, value: T) {
+        self.push_back_arc(Arc::new(value));
+    }
+
+    pub fn push_back_arc(&self, value: Arc<T>) {
+        let guard = &epoch::pin();
+
+        let node = Owned::new(LockFreeCell {
+            value: MaybeUninit::new(value),
+            right: Atomic::null(),
+        })
+        .into_shared(guard);
+
+        loop {
+            let tail = self.tail.load(Acquire, guard);
+            let right = unsafe { tail.deref() }.right.load(Acquire, guard);
+
+            if !right.is_null() {
+                let _ = self
+                    .tail
+                    .compare_exchange(tail, right, Release, Relaxed, guard);
+
+                continue;
+            }
+
+            if unsafe { tail.deref() }
+                .right
+                .compare_exchange(Shared::null(), node, Release, Relaxed, guard)
+                .is_ok()
+            {
+                let _ = self
+                    .tail
+                    .compare_exchange(tail, node, Release, Relaxed, guard);
+
+                break;
+            }
+        }
+
+        self.len.fetch_add(1, Relaxed);
+    }
+
+    pub fn pop_front(&self) -> Option<Arc<T>> {
+        let guard = &epoch::pin();
+
+        loop {
+            let head = self.head.load(Acquire, guard);
+            let right = unsafe { head.deref() }.right.load(Acquire, guard);
+
+            let node = unsafe { right.as_ref() }?;
+
+            if self
+                .head
+                .compare_exchange(head, right, Release, Relaxed, guard)
+                .is_ok()
+            {
+                let tail = self.tail.load(Relaxed, guard);
+
+                if tail == head {
+                    let _ = self
+                        .tail
+                        .compare_exchange(tail, right, Release, Relaxed, guard);
+                }
+
+                self.len.fetch_sub(1, Relaxed);
+
+                unsafe {
+                    guard.defer_destroy(head);
+
+                    break node.value.assume_init_read().into();
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for LockFreeQueue<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+
+        unsafe {
+            let guard = epoch::unprotected();
+
+            drop(self.head.load(Relaxed, guard).into_owned());
+        }
+    }
+}
+
+impl<T> Default for LockFreeQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for LockFreeQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LockFreeQueue")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> FromIterator<T> for LockFreeQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let list = Self::new();
+
+        for value in iter {
+            list.push_back(value);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use super::LockFreeQueue;
+
+    #[test]
+    fn push_pop() {
+        let list = (1..=3).collect::<LockFreeQueue<_>>();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_front().map(|a| *a), Some(1));
+
+        list.push_back(4);
+
+        assert_eq!(list.pop_front().map(|a| *a), Some(2));
+        assert_eq!(list.pop_front().map(|a| *a), Some(3));
+        assert_eq!(list.pop_front().map(|a| *a), Some(4));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn concurrent() {
+        let list = Arc::new(LockFreeQueue::new());
+
+        let producers = (0..4)
+            .map(|thread| {
+                let list = Arc::clone(&list);
+
+                thread::spawn(move || {
+                    for value in 0..10000u64 {
+                        list.push_back(thread * 10000 + value);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let consumers = (0..4)
+            .map(|_| {
+                let list = Arc::clone(&list);
+
+                thread::spawn(move || {
+                    let mut sum = 0u64;
+                    let mut count = 0;
+
+                    while count < 10000 {
+                        if let Some(value) = list.pop_front() {
+                            sum += *value;
+                            count += 1;
+                        }
+                    }
+
+                    sum
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        let sum = consumers
+            .into_iter()
+            .map(|consumer| consumer.join().unwrap())
+            .sum::<u64>();
+
+        assert_eq!(sum, (0..40000).sum::<u64>());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn drop() {
+        let value = Arc::new(());
+        let list = (0..100)
+            .map(|_| Arc::clone(&value))
+            .collect::<LockFreeQueue<_>>();
+
+        list.pop_front();
+
+        std::mem::drop(list);
+
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
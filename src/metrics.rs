@@ -0,0 +1,63 @@
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub pushes: usize,
+    pub pops: usize,
+    pub retries: usize,
+    pub spins: usize,
+    pub yields: usize,
+    pub parks: usize,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Counter {
+    Push,
+    Pop,
+    Retry,
+    Spin,
+    Yield,
+    Park,
+}
+
+static COUNTERS: [AtomicUsize; 6] = [const { AtomicUsize::new(0) }; 6];
+
+pub(crate) fn count(counter: Counter) {
+    COUNTERS[counter as usize].fetch_add(1, Relaxed);
+}
+
+pub fn stats() -> Stats {
+    let load = |counter: Counter| COUNTERS[counter as usize].load(Relaxed);
+
+    Stats {
+        pushes: load(Counter::Push),
+        pops: load(Counter::Pop),
+        retries: load(Counter::Retry),
+        spins: load(Counter::Spin),
+        yields: load(Counter::Yield),
+        parks: load(Counter::Park),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SharedList;
+
+    #[test]
+    fn counters() {
+        let before = stats();
+
+        let list = SharedList::new();
+
+        list.push_back(1).unwrap();
+        list.push_front(0).unwrap();
+        list.pop_back();
+
+        let after = stats();
+
+        assert!(after.pushes >= before.pushes + 2);
+        assert!(after.pops > before.pops);
+        assert!(after.retries >= before.retries);
+    }
+}
@@ -1,95 +1,282 @@
-use std::sync::{Arc, Mutex};
+use core::{fmt, mem, ptr};
 
-use crate::try_lock;
+use alloc::sync::{Arc, Weak};
+
+use crate::{
+    list::Inner,
+    sync::{Backoff, NodeLock},
+    try_lock, InsertError, SharedList,
+};
 
 #[derive(Debug)]
 pub(crate) struct Routes<T> {
+    pub value: Option<Arc<T>>,
     pub left: Option<Node<T>>,
     pub right: Option<Node<T>>,
+    pub owner: Weak<Inner<T>>,
 }
 
 impl<T> Routes<T> {
-    pub fn new(left: Node<T>, right: Node<T>) -> Self {
+    pub fn new(value: Arc<T>, left: Node<T>, right: Node<T>) -> Self {
         Self {
+            value: value.into(),
             left: left.into(),
             right: right.into(),
+            owner: Weak::new(),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            value: None,
+            left: None,
+            right: None,
+            owner: Weak::new(),
         }
     }
 
-    pub fn new_insulated() -> Self {
+    pub fn new_insulated(value: Arc<T>) -> Self {
         Self {
+            value: value.into(),
             left: None,
             right: None,
+            owner: Weak::new(),
         }
     }
 
-    pub fn from_left(left: Node<T>) -> Self {
+    pub fn from_left(value: Arc<T>, left: Node<T>) -> Self {
         Self {
+            value: value.into(),
             left: left.into(),
             right: None,
+            owner: Weak::new(),
         }
     }
 
-    pub fn from_right(right: Node<T>) -> Self {
+    pub fn from_right(value: Arc<T>, right: Node<T>) -> Self {
         Self {
+            value: value.into(),
             left: None,
             right: right.into(),
+            owner: Weak::new(),
         }
     }
 
+    pub fn value(&self) -> &Arc<T> {
+        self.value.as_ref().unwrap()
+    }
+
+    pub fn value_mut(&mut self) -> &mut Arc<T> {
+        self.value.as_mut().unwrap()
+    }
+
     pub fn is_insulate(&self) -> bool {
         self.left.is_none() && self.right.is_none()
     }
 }
 
-#[derive(Debug)]
 pub struct Node<T> {
-    pub(crate) routes: Arc<Mutex<Routes<T>>>,
-    pub(crate) value: Arc<T>,
+    pub(crate) routes: Arc<NodeLock<Routes<T>>>,
 }
 
 impl<T> Node<T> {
-    pub fn value(&self) -> &Arc<T> {
-        &self.value
+    pub fn value(&self) -> Arc<T> {
+        Arc::clone(self.routes.read().unwrap().value())
+    }
+
+    pub fn replace(&self, value: T) -> Arc<T> {
+        mem::replace(self.routes.lock().unwrap().value_mut(), Arc::new(value))
+    }
+
+    pub fn set(&self, value: T) {
+        self.replace(value);
+    }
+
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        Arc::get_mut(self.routes.lock().unwrap().value_mut()).map(f)
+    }
+
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        T: Clone,
+    {
+        f(Arc::make_mut(self.routes.lock().unwrap().value_mut()))
     }
 
     pub fn left(&self) -> Option<Node<T>> {
-        self.routes.lock().unwrap().left.clone()
+        self.routes.read().unwrap().left.clone()
     }
 
     pub fn right(&self) -> Option<Node<T>> {
-        self.routes.lock().unwrap().right.clone()
+        self.routes.read().unwrap().right.clone()
+    }
+
+    pub fn iter_left(&self) -> RevNodeIterator<T> {
+        RevNodeIterator {
+            node: self.clone().into(),
+        }
+    }
+
+    pub fn nth_left(&self, n: usize) -> Option<Node<T>> {
+        let mut node = self.clone();
+
+        for _ in 0..n {
+            node = node.left()?;
+        }
+
+        node.into()
+    }
+
+    pub fn nth_right(&self, n: usize) -> Option<Node<T>> {
+        let mut node = self.clone();
+
+        for _ in 0..n {
+            node = node.right()?;
+        }
+
+        node.into()
     }
 
     pub fn is_insulate(&self) -> bool {
-        self.routes.lock().unwrap().is_insulate()
+        self.routes.read().unwrap().is_insulate()
+    }
+
+    pub fn distance_to(&self, other: &Node<T>) -> Option<isize> {
+        let mut left = Some(self.clone());
+        let mut right = Some(self.clone());
+        let mut distance = 0;
+
+        while left.is_some() || right.is_some() {
+            if right.as_ref() == Some(other) {
+                return distance.into();
+            }
+
+            if left.as_ref() == Some(other) {
+                return (-distance).into();
+            }
+
+            left = left.and_then(|node| node.left());
+            right = right.and_then(|node| node.right());
+            distance += 1;
+        }
+
+        None
+    }
+
+    pub fn list(&self) -> Option<SharedList<T>> {
+        let owner = self.routes.read().unwrap().owner.upgrade()?;
+
+        SharedList::from_inner(owner).into()
+    }
+
+    pub fn insert_before(&self, mut value: T) -> Result<Node<T>, InsertError<T>> {
+        loop {
+            let Some(list) = self.list() else {
+                return Err(InsertError::Detached(value));
+            };
+
+            match list.insert_before(self, value) {
+                Err(InsertError::Detached(rejected)) => value = rejected,
+                result => return result,
+            }
+        }
+    }
+
+    pub fn insert_after(&self, mut value: T) -> Result<Node<T>, InsertError<T>> {
+        loop {
+            let Some(list) = self.list() else {
+                return Err(InsertError::Detached(value));
+            };
+
+            match list.insert_after(self, value) {
+                Err(InsertError::Detached(rejected)) => value = rejected,
+                result => return result,
+            }
+        }
+    }
+
+    pub fn downgrade(&self) -> WeakNode<T> {
+        WeakNode {
+            routes: Arc::downgrade(&self.routes),
+        }
+    }
+
+    pub(crate) fn is_owned_by(&self, inner: &Arc<Inner<T>>) -> bool {
+        ptr::eq(
+            self.routes.read().unwrap().owner.as_ptr(),
+            Arc::as_ptr(inner),
+        )
+    }
+
+    pub(crate) fn adopt(&self, owner: Weak<Inner<T>>) {
+        self.routes.lock().unwrap().owner = owner;
     }
 
-    pub(crate) fn from_routes(value: T, routes: Routes<T>) -> Self {
+    pub(crate) fn disown(&self) {
+        self.adopt(Weak::new());
+    }
+
+    pub(crate) fn from_routes(routes: Routes<T>) -> Self {
         Self {
-            routes: Arc::new(Mutex::new(routes)),
-            value: Arc::new(value),
+            routes: Arc::new(NodeLock::new(routes)),
+        }
+    }
+
+    pub(crate) fn from_storage(value: Arc<T>, routes: Arc<NodeLock<Routes<T>>>) -> Self {
+        routes.lock().unwrap().value = value.into();
+
+        Self { routes }
+    }
+
+    pub(crate) fn into_storage(self) -> Option<Arc<NodeLock<Routes<T>>>> {
+        let Self { mut routes } = self;
+
+        Arc::get_mut(&mut routes)?;
+
+        let mut self_routes = routes.lock().unwrap();
+
+        if !self_routes.is_insulate() {
+            return None;
         }
+
+        self_routes.value.take();
+
+        drop(self_routes);
+
+        routes.into()
     }
 
-    pub(crate) fn new(value: T, left: Node<T>, right: Node<T>) -> Self {
-        Self::from_routes(value, Routes::new(left, right))
+    pub(crate) fn new(value: Arc<T>, left: Node<T>, right: Node<T>) -> Self {
+        Self::from_routes(Routes::new(value, left, right))
     }
 
+    #[cfg(test)]
     pub(crate) fn new_insulated(value: T) -> Self {
-        Self::from_routes(value, Routes::new_insulated())
+        Self::new_insulated_arc(Arc::new(value))
     }
 
-    pub(crate) fn from_right(value: T, right: Node<T>) -> Self {
-        Self::from_routes(value, Routes::from_right(right))
+    pub(crate) fn new_insulated_arc(value: Arc<T>) -> Self {
+        Self::from_routes(Routes::new_insulated(value))
     }
 
-    pub(crate) fn from_left(value: T, left: Node<T>) -> Self {
-        Self::from_routes(value, Routes::from_left(left))
+    pub(crate) fn from_right(value: Arc<T>, right: Node<T>) -> Self {
+        Self::from_routes(Routes::from_right(value, right))
+    }
+
+    pub(crate) fn from_left(value: Arc<T>, left: Node<T>) -> Self {
+        Self::from_routes(Routes::from_left(value, left))
     }
 
     pub(crate) fn insert_left(&self, value: T) -> Node<T> {
+        self.insert_left_arc(Arc::new(value))
+    }
+
+    pub(crate) fn insert_left_arc(&self, value: Arc<T>) -> Node<T> {
+        let backoff = Backoff::new();
+
         loop {
+            backoff.snooze();
+
             let mut self_routes = self.routes.lock().unwrap();
 
             if let Some(left) = self_routes.left.take() {
@@ -119,6 +306,10 @@ impl<T> Node<T> {
     }
 
     pub(crate) fn insert_right(&self, value: T) -> Node<T> {
+        self.insert_right_arc(Arc::new(value))
+    }
+
+    pub(crate) fn insert_right_arc(&self, value: Arc<T>) -> Node<T> {
         let mut self_routes = self.routes.lock().unwrap();
 
         if let Some(right) = self_routes.right.take() {
@@ -139,8 +330,90 @@ impl<T> Node<T> {
         }
     }
 
-    pub(crate) fn insulate_left(&self) -> (&Arc<T>, Option<Node<T>>) {
+    pub(crate) fn try_insert_left(&self, value: T) -> Result<Node<T>, T> {
+        let mut self_routes = try_lock!(self.routes, return Err(value));
+
+        if let Some(left) = self_routes.left.clone() {
+            let mut left_routes = try_lock!(left.routes, return Err(value));
+
+            let mid = Node::new(Arc::new(value), left.clone(), self.clone());
+
+            self_routes.left = mid.clone().into();
+            left_routes.right = mid.clone().into();
+
+            Ok(mid)
+        } else {
+            let mid = Node::from_right(Arc::new(value), self.clone());
+
+            self_routes.left = mid.clone().into();
+
+            Ok(mid)
+        }
+    }
+
+    pub(crate) fn try_insert_right(&self, value: T) -> Result<Node<T>, T> {
+        let mut self_routes = try_lock!(self.routes, return Err(value));
+
+        if let Some(right) = self_routes.right.clone() {
+            let mut right_routes = try_lock!(right.routes, return Err(value));
+
+            let mid = Node::new(Arc::new(value), self.clone(), right.clone());
+
+            self_routes.right = mid.clone().into();
+            right_routes.left = mid.clone().into();
+
+            Ok(mid)
+        } else {
+            let mid = Node::from_left(Arc::new(value), self.clone());
+
+            self_routes.right = mid.clone().into();
+
+            Ok(mid)
+        }
+    }
+
+    pub(crate) fn splice_left(&self, first: &Node<T>, last: &Node<T>) {
+        let backoff = Backoff::new();
+
+        loop {
+            backoff.snooze();
+
+            let mut self_routes = self.routes.lock().unwrap();
+
+            if let Some(left) = self_routes.left.clone() {
+                let mut left_routes = try_lock!(left.routes);
+
+                first.routes.lock().unwrap().left = left.clone().into();
+                left_routes.right = first.clone().into();
+            }
+
+            last.routes.lock().unwrap().right = self.clone().into();
+            self_routes.left = last.clone().into();
+
+            break;
+        }
+    }
+
+    pub(crate) fn splice_right(&self, first: &Node<T>, last: &Node<T>) {
+        let mut self_routes = self.routes.lock().unwrap();
+
+        if let Some(right) = self_routes.right.take() {
+            let mut right_routes = right.routes.lock().unwrap();
+
+            last.routes.lock().unwrap().right = right.clone().into();
+            right_routes.left = last.clone().into();
+        }
+
+        first.routes.lock().unwrap().left = self.clone().into();
+        self_routes.right = first.clone().into();
+    }
+
+    pub(crate) fn insulate_left(&self) -> (Arc<T>, Option<Node<T>>) {
+        let backoff = Backoff::new();
+
         loop {
+            backoff.snooze();
+
             let mut self_routes = self.routes.lock().unwrap();
 
             if let Some(left) = self_routes.left.as_ref() {
@@ -149,11 +422,13 @@ impl<T> Node<T> {
                 left_routes.right = self_routes.right.clone();
             }
 
-            break (&self.value, self_routes.left.take());
+            self_routes.owner = Weak::new();
+
+            break (self_routes.value().clone(), self_routes.left.take());
         }
     }
 
-    pub(crate) fn insulate_right(&self) -> (&Arc<T>, Option<Node<T>>) {
+    pub(crate) fn insulate_right(&self) -> (Arc<T>, Option<Node<T>>) {
         let mut self_routes = self.routes.lock().unwrap();
 
         if let Some(right) = self_routes.right.as_ref() {
@@ -162,11 +437,61 @@ impl<T> Node<T> {
             right_routes.left = self_routes.left.clone();
         }
 
-        (&self.value, self_routes.right.take())
+        self_routes.owner = Weak::new();
+
+        (self_routes.value().clone(), self_routes.right.take())
+    }
+
+    pub(crate) fn steal_right(&self) -> Option<(Arc<T>, Node<T>)> {
+        let mut self_routes = self.routes.lock().unwrap();
+
+        let right = self_routes.right.as_ref()?;
+        let mut right_routes = right.routes.lock().unwrap();
+
+        right_routes.right.as_ref()?;
+        right_routes.left = self_routes.left.clone();
+
+        drop(right_routes);
+
+        self_routes.owner = Weak::new();
+
+        Some((self_routes.value().clone(), self_routes.right.take()?))
     }
 
-    pub(crate) fn insulate(&self) -> (&Arc<T>, Option<Node<T>>, Option<Node<T>>) {
+    pub(crate) fn try_insulate_left(&self) -> Option<(Arc<T>, Option<Node<T>>)> {
+        let mut self_routes = try_lock!(self.routes, return None);
+
+        if let Some(left) = self_routes.left.as_ref() {
+            let mut left_routes = try_lock!(left.routes, return None);
+
+            left_routes.right = self_routes.right.clone();
+        }
+
+        self_routes.owner = Weak::new();
+
+        Some((self_routes.value().clone(), self_routes.left.take()))
+    }
+
+    pub(crate) fn try_insulate_right(&self) -> Option<(Arc<T>, Option<Node<T>>)> {
+        let mut self_routes = try_lock!(self.routes, return None);
+
+        if let Some(right) = self_routes.right.as_ref() {
+            let mut right_routes = try_lock!(right.routes, return None);
+
+            right_routes.left = self_routes.left.clone();
+        }
+
+        self_routes.owner = Weak::new();
+
+        Some((self_routes.value().clone(), self_routes.right.take()))
+    }
+
+    pub(crate) fn insulate(&self) -> (Arc<T>, Option<Node<T>>, Option<Node<T>>) {
+        let backoff = Backoff::new();
+
         loop {
+            backoff.snooze();
+
             let mut self_routes = self.routes.lock().unwrap();
 
             let left_guard = if let Some(left) = self_routes.left.as_ref() {
@@ -187,30 +512,48 @@ impl<T> Node<T> {
 
             drop(left_guard);
 
+            self_routes.owner = Weak::new();
+
             break (
-                &self.value,
+                self_routes.value().clone(),
                 self_routes.left.take(),
                 self_routes.right.take(),
             );
         }
     }
 
-    pub(crate) fn insulate_left_owned(&self) -> (Arc<T>, Option<Node<T>>) {
-        let (v, l) = self.insulate_left();
+    pub(crate) fn set_left(&self, left: Option<Node<T>>) {
+        self.routes.lock().unwrap().left = left;
+    }
 
-        (Arc::clone(v), l)
+    pub(crate) fn set_right(&self, right: Option<Node<T>>) {
+        self.routes.lock().unwrap().right = right;
     }
 
-    pub(crate) fn insulate_right_owned(&self) -> (Arc<T>, Option<Node<T>>) {
-        let (v, r) = self.insulate_right();
+    pub(crate) fn split_left(&self) -> Option<Node<T>> {
+        let backoff = Backoff::new();
+
+        loop {
+            backoff.snooze();
+
+            let mut self_routes = self.routes.lock().unwrap();
+
+            if let Some(left) = self_routes.left.as_ref() {
+                let mut left_routes = try_lock!(left.routes);
 
-        (Arc::clone(v), r)
+                left_routes.right.take();
+            }
+
+            break self_routes.left.take();
+        }
     }
 
-    pub(crate) fn insulate_owned(&self) -> (Arc<T>, Option<Node<T>>, Option<Node<T>>) {
-        let (v, l, r) = self.insulate();
+    pub(crate) fn unlink(&self) -> Option<Node<T>> {
+        let mut self_routes = self.routes.lock().unwrap();
 
-        (Arc::clone(v), l, r)
+        self_routes.owner = Weak::new();
+        self_routes.left.take();
+        self_routes.right.take()
     }
 }
 
@@ -218,17 +561,70 @@ impl<T> Clone for Node<T> {
     fn clone(&self) -> Self {
         Self {
             routes: Arc::clone(&self.routes),
-            value: Arc::clone(&self.value),
         }
     }
 }
 
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let routes = self.routes.read().unwrap();
+
+        f.debug_struct("Node")
+            .field("value", routes.value())
+            .field("left", &routes.left.is_some())
+            .field("right", &routes.right.is_some())
+            .finish()
+    }
+}
+
 impl<T> PartialEq for Node<T> {
     fn eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.routes, &other.routes)
     }
 }
 
+pub struct WeakNode<T> {
+    routes: Weak<NodeLock<Routes<T>>>,
+}
+
+impl<T> WeakNode<T> {
+    pub fn new() -> Self {
+        Self {
+            routes: Weak::new(),
+        }
+    }
+
+    pub fn upgrade(&self) -> Option<Node<T>> {
+        Some(Node {
+            routes: self.routes.upgrade()?,
+        })
+    }
+
+    pub fn ptr_eq(&self, other: &WeakNode<T>) -> bool {
+        Weak::ptr_eq(&self.routes, &other.routes)
+    }
+}
+
+impl<T> Default for WeakNode<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for WeakNode<T> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: Weak::clone(&self.routes),
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(WeakNode)")
+    }
+}
+
 pub struct NodeIterator<T> {
     node: Option<Node<T>>,
 }
@@ -249,7 +645,25 @@ impl<T> Iterator for NodeIterator<T> {
         if let Some(node) = self.node.take() {
             self.node = node.right();
 
-            Some(node.value)
+            Some(node.value())
+        } else {
+            None
+        }
+    }
+}
+
+pub struct RevNodeIterator<T> {
+    node: Option<Node<T>>,
+}
+
+impl<T> Iterator for RevNodeIterator<T> {
+    type Item = Arc<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.node.take() {
+            self.node = node.left();
+
+            Some(node.value())
         } else {
             None
         }
@@ -260,6 +674,20 @@ impl<T> Iterator for NodeIterator<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn replace() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2);
+
+        assert_eq!(*tail.replace(3), 2);
+
+        head.set(0);
+
+        assert_eq!(*head.value(), 0);
+        assert_eq!(head.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![0, 3]);
+        assert_eq!(mem::size_of::<Node<i32>>(), mem::size_of::<usize>());
+    }
+
     #[test]
     fn insert_left() {
         let node = Node::new_insulated(2);
@@ -276,6 +704,90 @@ mod tests {
         assert_eq!(head.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![1, 2]);
     }
 
+    #[test]
+    fn splice_left() {
+        let four = Node::new_insulated(4);
+        let head = four.insert_left(1);
+
+        let two = Node::new_insulated(2);
+        let thr = two.insert_right(3);
+
+        four.splice_left(&two, &thr);
+
+        assert_eq!(
+            head.into_iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(four.left().unwrap(), thr);
+    }
+
+    #[test]
+    fn splice_right() {
+        let head = Node::new_insulated(1);
+        let four = head.insert_right(4);
+
+        let two = Node::new_insulated(2);
+        let thr = two.insert_right(3);
+
+        head.splice_right(&two, &thr);
+
+        assert_eq!(
+            head.into_iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(four.left().unwrap(), thr);
+    }
+
+    #[test]
+    fn try_insert() {
+        let mid = Node::new_insulated(2);
+        let head = mid.try_insert_left(1).unwrap();
+        mid.try_insert_right(3).unwrap();
+
+        let guard = head.routes.lock().unwrap();
+
+        assert_eq!(mid.try_insert_left(0).unwrap_err(), 0);
+        assert!(mid.try_insert_right(4).is_ok());
+
+        drop(guard);
+
+        assert_eq!(
+            head.into_iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 2, 4, 3]
+        );
+    }
+
+    #[cfg(feature = "rwlock")]
+    #[test]
+    fn shared_reads() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2);
+
+        let routes = head.routes.read().unwrap();
+        let value = tail.routes.read().unwrap();
+
+        assert_eq!(head.right(), Some(tail.clone()));
+        assert_eq!(*tail.value(), 2);
+        assert_eq!(routes.right, Some(tail.clone()));
+        assert_eq!(**value.value(), 2);
+    }
+
+    #[test]
+    fn try_insulate() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2);
+
+        let guard = head.routes.lock().unwrap();
+
+        assert!(tail.try_insulate_left().is_none());
+        assert!(head.try_insulate_right().is_none());
+
+        drop(guard);
+
+        assert_eq!(tail.try_insulate_left().unwrap().1, Some(head.clone()));
+        assert_eq!(head.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![1]);
+    }
+
     #[test]
     fn insulate_left() {
         let tail = Node::new_insulated(2);
@@ -302,12 +814,119 @@ mod tests {
         let head = mid.insert_left(1);
         mid.insert_right(3);
 
-        assert_eq!(**mid.insulate().0, 2);
+        assert_eq!(*mid.insulate().0, 2);
         assert!(mid.is_insulate());
 
         assert_eq!(head.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![1, 3]);
     }
 
+    #[test]
+    fn split_left() {
+        let head = Node::new_insulated(1);
+        let mid = head.insert_right(2);
+        mid.insert_right(3);
+
+        assert_eq!(mid.split_left().unwrap(), head);
+        assert_eq!(mid.left(), None);
+
+        assert_eq!(head.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(mid.into_iter().map(|a| *a).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn iter_left() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2).insert_right(3);
+
+        assert_eq!(
+            tail.iter_left().map(|a| *a).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(head.iter_left().map(|a| *a).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn nth() {
+        let head = Node::new_insulated(1);
+        let mid = head.insert_right(2);
+        let tail = mid.insert_right(3);
+
+        assert_eq!(head.nth_right(0), Some(head.clone()));
+        assert_eq!(head.nth_right(2), Some(tail.clone()));
+        assert_eq!(head.nth_right(3), None);
+        assert_eq!(tail.nth_left(1), Some(mid));
+        assert_eq!(tail.nth_left(2), Some(head));
+        assert_eq!(tail.nth_left(3), None);
+    }
+
+    #[test]
+    fn distance_to() {
+        let head = Node::new_insulated(1);
+        let mid = head.insert_right(2);
+        let tail = mid.insert_right(3);
+
+        assert_eq!(head.distance_to(&head), Some(0));
+        assert_eq!(head.distance_to(&tail), Some(2));
+        assert_eq!(tail.distance_to(&head), Some(-2));
+        assert_eq!(mid.distance_to(&head), Some(-1));
+        assert_eq!(head.distance_to(&Node::new_insulated(4)), None);
+    }
+
+    #[test]
+    fn with_mut() {
+        let node = Node::new_insulated(1);
+
+        assert_eq!(node.with_mut(|a| mem::replace(a, 2)), Some(1));
+
+        let value = node.value();
+
+        assert_eq!(node.with_mut(|a| *a = 3), None);
+        assert_eq!(node.update(|a| mem::replace(a, 4)), 2);
+        assert_eq!(*value, 2);
+        assert_eq!(*node.value(), 4);
+    }
+
+    #[test]
+    fn downgrade() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2).downgrade();
+
+        assert_eq!(tail.upgrade().map(|node| *node.value()), Some(2));
+        assert!(tail.ptr_eq(&tail.clone()));
+
+        head.unlink();
+
+        assert_eq!(tail.upgrade(), None);
+        assert_eq!(WeakNode::<i32>::new().upgrade(), None);
+    }
+
+    #[test]
+    fn unlink() {
+        let head = Node::new_insulated(1);
+        let mid = head.insert_right(2);
+        let tail = mid.insert_right(3);
+
+        assert_eq!(mid.unlink().unwrap(), tail);
+        assert!(mid.is_insulate());
+        assert_eq!(head.right().unwrap(), mid);
+        assert_eq!(tail.left().unwrap(), mid);
+    }
+
+    #[test]
+    fn debug() {
+        let head = Node::new_insulated(1);
+        let tail = head.insert_right(2);
+
+        assert_eq!(
+            format!("{head:?}"),
+            "Node { value: 1, left: false, right: true }"
+        );
+        assert_eq!(
+            format!("{tail:?}"),
+            "Node { value: 2, left: true, right: false }"
+        );
+    }
+
     #[test]
     fn insert_right_insert_left() {
         use std::thread;
@@ -431,3 +1050,110 @@ mod tests {
         }
     }
 }
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::{model::Builder, thread};
+
+    use super::Node;
+
+    fn model(f: impl Fn() + Sync + Send + 'static) {
+        let mut builder = Builder::new();
+
+        builder.max_branches = 10_000;
+        builder.preemption_bound = Some(3);
+        builder.check(f);
+    }
+
+    fn assert_chain(head: &Node<i32>, values: &[i32]) {
+        let mut nodes = vec![head.clone()];
+
+        while let Some(right) = nodes.last().unwrap().right() {
+            assert_eq!(right.left().as_ref(), nodes.last());
+
+            nodes.push(right);
+        }
+
+        assert_eq!(head.left(), None);
+        assert_eq!(
+            nodes.iter().map(|node| *node.value()).collect::<Vec<_>>(),
+            values
+        );
+
+        for node in nodes {
+            node.unlink();
+        }
+    }
+
+    #[test]
+    fn insert_left_insert_right() {
+        model(|| {
+            let head = Node::new_insulated(1);
+            let tail = head.insert_right(3);
+
+            let left = thread::spawn({
+                let tail = tail.clone();
+
+                move || {
+                    tail.insert_left(2);
+                }
+            });
+
+            head.insert_right(4);
+
+            left.join().unwrap();
+
+            let mid = head.right().unwrap();
+
+            if *mid.value() == 2 {
+                assert_chain(&head, &[1, 2, 4, 3]);
+            } else {
+                assert_chain(&head, &[1, 4, 2, 3]);
+            }
+        });
+    }
+
+    #[test]
+    fn insulate_insert_left() {
+        model(|| {
+            let head = Node::new_insulated(1);
+            let mid = head.insert_right(2);
+            let tail = mid.insert_right(3);
+
+            let insulate = thread::spawn({
+                let mid = mid.clone();
+
+                move || *mid.insulate().0
+            });
+
+            tail.insert_left(4);
+
+            assert_eq!(insulate.join().unwrap(), 2);
+            assert!(mid.is_insulate());
+
+            assert_chain(&head, &[1, 4, 3]);
+        });
+    }
+
+    #[test]
+    fn insulate_left_insulate_right() {
+        model(|| {
+            let head = Node::new_insulated(1);
+            let mid = head.insert_right(2);
+            let tail = mid.insert_right(3);
+
+            let back = thread::spawn({
+                let tail = tail.clone();
+
+                move || tail.insulate_left().1
+            });
+
+            let (_, right) = head.insulate_right();
+
+            assert_eq!(right.as_ref(), Some(&mid));
+            assert_eq!(back.join().unwrap().as_ref(), Some(&mid));
+
+            assert_chain(&mid, &[2]);
+        });
+    }
+}
@@ -0,0 +1,291 @@
+use core::fmt;
+
+use alloc::vec::Vec;
+
+use crate::{Allocator, Global, InlineIntoIter, InlineIter, InlineIterMut, InlineList, SharedList};
+
+pub struct LinkedList<T, A: Allocator = Global> {
+    list: InlineList<T, A>,
+}
+
+impl<T> LinkedList<T> {
+    pub const fn new() -> Self {
+        Self {
+            list: InlineList::new(),
+        }
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Nodes are allocated from `alloc`. Only the owned list takes an
+    /// allocator; a `SharedList` built from it with `into_shared` allocates
+    /// from the global heap.
+    #[cfg(feature = "allocator-api2")]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            list: InlineList::new_in(alloc),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut()
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.list.append(&mut other.list);
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|other| other == value)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    pub fn iter(&self) -> InlineIter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> InlineIterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    pub fn into_shared(self) -> SharedList<T> {
+        self.list.into_iter().collect()
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for LinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for LinkedList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.list == other.list
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for LinkedList<T, A> {}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            list: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<SharedList<T>> for LinkedList<T> {
+    fn from(list: SharedList<T>) -> Self {
+        list.into_owned()
+    }
+}
+
+impl<T, A: Allocator> From<LinkedList<T, A>> for SharedList<T> {
+    fn from(list: LinkedList<T, A>) -> Self {
+        list.into_shared()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = InlineIntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = InlineIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = InlineIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_new() {
+        static EMPTY: LinkedList<i32> = LinkedList::new();
+
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.front(), None);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = LinkedList::new();
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert!(list.contains(&2));
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        *list.back_mut().unwrap() += 1;
+
+        let mut other = LinkedList::from(vec![5, 6]);
+
+        list.append(&mut other);
+
+        assert!(other.is_empty());
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 4, 5, 6]
+        );
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(6));
+        assert_eq!(format!("{list:?}"), "[2, 4, 5]");
+    }
+
+    #[test]
+    fn shared() {
+        let owned = (1..=3).collect::<LinkedList<_>>();
+        let shared = SharedList::from(owned.clone());
+        let handle = shared.handle();
+
+        handle.push_back(4).unwrap();
+
+        assert_eq!(shared.len(), 4);
+        assert_eq!(
+            shared.iter().map(|value| *value).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        let front = shared.front().unwrap();
+        let owned = LinkedList::from(shared);
+
+        assert_eq!(owned.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(*front, 1);
+        assert!(handle.is_empty());
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn new_in() {
+        use core::cell::Cell;
+
+        use crate::allocator::Counting;
+
+        let live = Cell::new(0);
+
+        {
+            let mut list = LinkedList::new_in(Counting(&live));
+
+            list.push_back(1);
+            list.push_front(0);
+
+            assert_eq!(live.get(), 2);
+            assert_eq!(list.pop_back(), Some(1));
+            assert_eq!(live.get(), 1);
+
+            let shared = SharedList::from(list);
+
+            assert_eq!(live.get(), 0);
+            assert_eq!(shared.len(), 1);
+        }
+
+        assert_eq!(live.get(), 0);
+    }
+
+    #[test]
+    fn list_macro() {
+        let empty: LinkedList<i32> = crate::list![];
+        let list = crate::list![1, 2, 3,];
+        let repeated = crate::list![String::from("a"); 3];
+
+        assert!(empty.is_empty());
+        assert_eq!(list, LinkedList::from(vec![1, 2, 3]));
+        assert_eq!(repeated.len(), 3);
+        assert_eq!(
+            repeated.iter().map(String::as_str).collect::<String>(),
+            "aaa"
+        );
+        assert!(crate::list![0; 0].is_empty());
+    }
+}
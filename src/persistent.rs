@@ -0,0 +1,198 @@
+use core::fmt;
+
+use alloc::{sync::Arc, vec::Vec};
+
+struct Cell<T> {
+    value: T,
+    next: Option<Arc<Cell<T>>>,
+}
+
+pub struct PersistentList<T> {
+    head: Option<Arc<Cell<T>>>,
+    len: usize,
+}
+
+impl<T> PersistentList<T> {
+    pub const fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|cell| &cell.value)
+    }
+
+    pub fn push_front(&self, value: T) -> Self {
+        Self {
+            head: Arc::new(Cell {
+                value,
+                next: self.head.clone(),
+            })
+            .into(),
+            len: self.len + 1,
+        }
+    }
+
+    pub fn pop_front(&self) -> Option<(&T, Self)> {
+        let cell = self.head.as_ref()?;
+
+        let rest = Self {
+            head: cell.next.clone(),
+            len: self.len - 1,
+        };
+
+        Some((&cell.value, rest))
+    }
+
+    pub fn ptr_eq(&self, other: &PersistentList<T>) -> bool {
+        match (&self.head, &other.head) {
+            (Some(head), Some(other)) => Arc::ptr_eq(head, other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn iter(&self) -> PersistentIter<'_, T> {
+        PersistentIter {
+            next: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+
+        while let Some(cell) = next {
+            next = match Arc::try_unwrap(cell) {
+                Ok(mut cell) => cell.next.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PersistentList<T> {}
+
+impl<T> FromIterator<T> for PersistentList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = iter.into_iter().collect::<Vec<_>>();
+
+        values
+            .into_iter()
+            .rev()
+            .fold(Self::new(), |list, value| list.push_front(value))
+    }
+}
+
+pub struct PersistentIter<'a, T> {
+    next: Option<&'a Cell<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for PersistentIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.next?;
+
+        self.next = cell.next.as_deref();
+        self.len -= 1;
+
+        Some(&cell.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for PersistentIter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a PersistentList<T> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions() {
+        let empty = PersistentList::new();
+        let one = empty.push_front(1);
+        let two = one.push_front(2);
+        let other = one.push_front(3);
+
+        assert_eq!(empty.len(), 0);
+        assert_eq!(two.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(other.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+
+        let (front, rest) = two.pop_front().unwrap();
+
+        assert_eq!(*front, 2);
+        assert!(rest.ptr_eq(&one));
+        assert!(other.pop_front().unwrap().1.ptr_eq(&one));
+        assert_eq!(empty.pop_front().map(|(front, _)| *front), None);
+    }
+
+    #[test]
+    fn collect() {
+        let list = (1..=3).collect::<PersistentList<_>>();
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.clone(), list);
+        assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn drop_long() {
+        let list = (0..100_000).collect::<PersistentList<_>>();
+        let shared = list.pop_front().unwrap().1;
+
+        drop(list);
+
+        assert_eq!(shared.len(), 99_999);
+    }
+}
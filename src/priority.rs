@@ -0,0 +1,144 @@
+use core::{cmp::Ordering, fmt};
+
+use alloc::sync::Arc;
+
+use crate::SortedList;
+
+struct Entry<T, P> {
+    value: Arc<T>,
+    priority: P,
+}
+
+impl<T, P: Ord> PartialEq for Entry<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<T, P: Ord> Eq for Entry<T, P> {}
+
+impl<T, P: Ord> PartialOrd for Entry<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, P: Ord> Ord for Entry<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<T: fmt::Debug, P: fmt::Debug> fmt::Debug for Entry<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("")
+            .field(&self.value)
+            .field(&self.priority)
+            .finish()
+    }
+}
+
+pub struct PriorityQueue<T, P> {
+    list: SortedList<Entry<T, P>>,
+}
+
+impl<T, P: Ord> PriorityQueue<T, P> {
+    pub fn new() -> Self {
+        Self {
+            list: SortedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn push(&self, value: T, priority: P) {
+        self.list.insert_sorted(Entry {
+            value: Arc::new(value),
+            priority,
+        });
+    }
+
+    pub fn peek(&self) -> Option<Arc<T>> {
+        self.list.min().map(|entry| Arc::clone(&entry.value))
+    }
+
+    pub fn pop(&self) -> Option<Arc<T>> {
+        self.list.pop_min().map(|entry| Arc::clone(&entry.value))
+    }
+
+    pub fn clear(&self) {
+        self.list.clear();
+    }
+}
+
+impl<T, P: Ord> Default for PriorityQueue<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, P: Ord + fmt::Debug> fmt::Debug for PriorityQueue<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+impl<T, P: Ord> FromIterator<(T, P)> for PriorityQueue<T, P> {
+    fn from_iter<I: IntoIterator<Item = (T, P)>>(iter: I) -> Self {
+        Self {
+            list: iter
+                .into_iter()
+                .map(|(value, priority)| Entry {
+                    value: Arc::new(value),
+                    priority,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let queue = PriorityQueue::new();
+
+        queue.push("low", 1);
+        queue.push("high", 3);
+        queue.push("mid", 2);
+        queue.push("also high", 3);
+
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.peek().as_deref(), Some(&"high"));
+        assert_eq!(queue.pop().as_deref(), Some(&"high"));
+        assert_eq!(queue.pop().as_deref(), Some(&"also high"));
+        assert_eq!(queue.pop().as_deref(), Some(&"mid"));
+        assert_eq!(queue.pop().as_deref(), Some(&"low"));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn from_iter() {
+        let queue = [('a', 2), ('b', 5), ('c', 2), ('d', 0)]
+            .into_iter()
+            .collect::<PriorityQueue<_, _>>();
+
+        assert_eq!(
+            format!("{queue:?}"),
+            "[('b', 5), ('a', 2), ('c', 2), ('d', 0)]"
+        );
+
+        queue.clear();
+
+        assert_eq!(queue.peek(), None);
+    }
+}
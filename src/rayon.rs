@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use ::rayon::iter::{
+    plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+
+use crate::SharedList;
+
+pub struct ParIter<T> {
+    values: Vec<Arc<T>>,
+}
+
+impl<T: Send + Sync> SharedList<T> {
+    pub fn par_iter(&self) -> ParIter<T> {
+        ParIter {
+            values: self.to_vec(),
+        }
+    }
+}
+
+impl<T: Send + Sync> ParallelIterator for ParIter<T> {
+    type Item = Arc<T>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.values.into_par_iter().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+impl<T: Send + Sync> IndexedParallelIterator for ParIter<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.values.into_par_iter().drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.values.into_par_iter().with_producer(callback)
+    }
+}
+
+impl<T: Send + Sync> IntoParallelIterator for &SharedList<T> {
+    type Item = Arc<T>;
+    type Iter = ParIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+    use crate::SharedList;
+
+    #[test]
+    fn par_iter() {
+        let list = (1..=1000).collect::<SharedList<u64>>();
+
+        assert_eq!(list.par_iter().len(), 1000);
+        assert_eq!(list.par_iter().map(|a| *a).sum::<u64>(), 500500);
+        assert_eq!(
+            (&list).into_par_iter().map(|a| *a * 2).collect::<Vec<_>>(),
+            (1..=1000).map(|a| a * 2).collect::<Vec<_>>()
+        );
+    }
+}
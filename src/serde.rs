@@ -0,0 +1,71 @@
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::SharedList;
+
+impl<T: Serialize> Serialize for SharedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+
+        for value in self.iter() {
+            seq.serialize_element(&*value)?;
+        }
+
+        seq.end()
+    }
+}
+
+struct LinkedListVisitor<T> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<T> {
+    type Value = SharedList<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let list = SharedList::new();
+
+        while let Some(value) = seq.next_element()? {
+            let _ = list.push_back(value);
+        }
+
+        Ok(list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SharedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LinkedListVisitor {
+            _value: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SharedList;
+
+    #[test]
+    fn serialize() {
+        let list = SharedList::from(vec![1, 2, 3]);
+
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3]");
+    }
+
+    #[test]
+    fn deserialize() {
+        let list: SharedList<i32> = serde_json::from_str("[1,2,3]").unwrap();
+
+        assert_eq!(list, SharedList::from(vec![1, 2, 3]));
+        assert_eq!(*list.tail().unwrap().value(), 3);
+    }
+}
@@ -0,0 +1,107 @@
+use core::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Sink;
+
+use crate::{sync::blocking, Closed, SharedList};
+
+pub struct IntoSink<T> {
+    list: SharedList<T>,
+    claimed: bool,
+}
+
+impl<T> SharedList<T> {
+    pub fn into_sink(self) -> IntoSink<T> {
+        IntoSink {
+            list: self,
+            claimed: false,
+        }
+    }
+}
+
+impl<T> Sink<T> for IntoSink<T> {
+    type Error = Closed<()>;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.claimed {
+            return Poll::Ready(Ok(()));
+        }
+
+        let poll = self.list.poll_claim(cx);
+
+        self.claimed = matches!(poll, Poll::Ready(Ok(())));
+
+        poll
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        assert!(
+            mem::take(&mut self.claimed),
+            "start_send called without poll_ready"
+        );
+
+        self.list.push_back_claimed(item);
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.list.close();
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> Drop for IntoSink<T> {
+    fn drop(&mut self) {
+        if self.claimed {
+            self.list.release_claim();
+        }
+    }
+}
+
+blocking! {
+    #[cfg(test)]
+    mod tests {
+        use std::thread;
+
+        use futures::{executor::block_on, stream, SinkExt};
+
+        use crate::{Closed, SharedList};
+
+        #[test]
+        fn into_sink() {
+            let list = SharedList::with_capacity_bound(1);
+
+            let consumer = thread::spawn({
+                let list = list.clone();
+
+                move || {
+                    let mut values = Vec::new();
+
+                    while let Some(value) = list.pop_front_blocking() {
+                        values.push(*value);
+                    }
+
+                    values
+                }
+            });
+
+            let mut sink = list.clone().into_sink();
+
+            block_on(sink.send_all(&mut stream::iter((1..=4).map(Ok)))).unwrap();
+            block_on(sink.close()).unwrap();
+
+            assert_eq!(consumer.join().unwrap(), vec![1, 2, 3, 4]);
+            assert_eq!(block_on(sink.send(5)), Err(Closed(())));
+            assert!(list.is_empty());
+        }
+    }
+}
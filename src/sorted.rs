@@ -0,0 +1,342 @@
+use core::{cmp::Ordering, fmt};
+
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::{sync::Mutex, InsertError, Iter, Node, OutOfOrder, SharedList};
+
+pub struct SortedNode<T> {
+    node: Node<T>,
+}
+
+impl<T> SortedNode<T> {
+    pub fn value(&self) -> Arc<T> {
+        self.node.value()
+    }
+
+    pub fn left(&self) -> Option<SortedNode<T>> {
+        self.node.left().map(SortedNode::from)
+    }
+
+    pub fn right(&self) -> Option<SortedNode<T>> {
+        self.node.right().map(SortedNode::from)
+    }
+
+    pub fn is_insulate(&self) -> bool {
+        self.node.is_insulate()
+    }
+}
+
+impl<T> From<Node<T>> for SortedNode<T> {
+    fn from(node: Node<T>) -> Self {
+        Self { node }
+    }
+}
+
+impl<T> Clone for SortedNode<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for SortedNode<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SortedNode").field(&self.value()).finish()
+    }
+}
+
+pub struct SortedList<T> {
+    list: SharedList<T>,
+    order: Mutex<()>,
+}
+
+impl<T: Ord> SortedList<T> {
+    pub fn new() -> Self {
+        Self {
+            list: SharedList::new(),
+            order: Mutex::new(()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn head(&self) -> Option<SortedNode<T>> {
+        self.list.head().map(SortedNode::from)
+    }
+
+    pub fn tail(&self) -> Option<SortedNode<T>> {
+        self.list.tail().map(SortedNode::from)
+    }
+
+    pub fn min(&self) -> Option<Arc<T>> {
+        self.list.front()
+    }
+
+    pub fn max(&self) -> Option<Arc<T>> {
+        self.list.back()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter()
+            .take_while(|other| **other <= *value)
+            .any(|other| *other == *value)
+    }
+
+    pub fn binary_search(&self, value: &T) -> Result<SortedNode<T>, Option<SortedNode<T>>> {
+        self.binary_search_by(|probe| probe.cmp(value))
+    }
+
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(
+        &self,
+        mut f: F,
+    ) -> Result<SortedNode<T>, Option<SortedNode<T>>> {
+        let _order = self.order.lock().unwrap();
+
+        let mut front = self.list.head();
+        let mut back = self.list.tail();
+
+        let found = loop {
+            let Some(node) = front else {
+                break Err(None);
+            };
+
+            match f(&node.value()) {
+                Ordering::Less => front = node.right(),
+                Ordering::Equal => break Ok(node),
+                Ordering::Greater => break Err(Some(node)),
+            }
+
+            if let Some(node) = back {
+                match f(&node.value()) {
+                    Ordering::Less => break Err(node.right()),
+                    Ordering::Equal => break Ok(node),
+                    Ordering::Greater => back = node.left(),
+                }
+            }
+        };
+
+        found
+            .map(SortedNode::from)
+            .map_err(|node| node.map(SortedNode::from))
+    }
+
+    pub fn insert_sorted(&self, value: T) -> SortedNode<T> {
+        let _order = self.order.lock().unwrap();
+
+        let mut next = self.list.head();
+
+        while let Some(node) = next.take_if(|node| *node.value() <= value) {
+            next = node.right();
+        }
+
+        let node = match next {
+            Some(node) => self.list.insert_before(&node, value),
+            None => self.list.push_back(value).map_err(InsertError::from),
+        };
+
+        node.map(SortedNode::from)
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    pub fn push_front(&self, value: T) -> Result<SortedNode<T>, OutOfOrder<T>> {
+        let _order = self.order.lock().unwrap();
+
+        if self.list.front().is_some_and(|front| value > *front) {
+            return Err(OutOfOrder(value));
+        }
+
+        Ok(self
+            .list
+            .push_front(value)
+            .map(SortedNode::from)
+            .unwrap_or_else(|_| unreachable!()))
+    }
+
+    pub fn push_back(&self, value: T) -> Result<SortedNode<T>, OutOfOrder<T>> {
+        let _order = self.order.lock().unwrap();
+
+        if self.list.back().is_some_and(|back| value < *back) {
+            return Err(OutOfOrder(value));
+        }
+
+        Ok(self
+            .list
+            .push_back(value)
+            .map(SortedNode::from)
+            .unwrap_or_else(|_| unreachable!()))
+    }
+
+    pub fn pop_min(&self) -> Option<Arc<T>> {
+        let _order = self.order.lock().unwrap();
+
+        self.list.pop_front()
+    }
+
+    pub fn pop_max(&self) -> Option<Arc<T>> {
+        let _order = self.order.lock().unwrap();
+
+        self.list.pop_back()
+    }
+
+    pub fn remove(&self, node: &SortedNode<T>) -> Option<Arc<T>> {
+        let _order = self.order.lock().unwrap();
+
+        self.list.remove(&node.node)
+    }
+
+    pub fn clear(&self) {
+        let _order = self.order.lock().unwrap();
+
+        self.list.clear();
+    }
+
+    pub fn into_inner(self) -> SharedList<T> {
+        self.list
+    }
+}
+
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = iter.into_iter().collect::<Vec<_>>();
+
+        values.sort();
+
+        Self {
+            list: SharedList::from(values),
+            order: Mutex::new(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn insert_sorted() {
+        let list = SortedList::new();
+
+        for value in [5, 1, 4, 1, 3] {
+            list.insert_sorted(value);
+        }
+
+        let node = list.insert_sorted(2);
+
+        assert_eq!(node.left().map(|node| *node.value()), Some(1));
+        assert_eq!(node.right().map(|node| *node.value()), Some(3));
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            vec![1, 1, 2, 3, 4, 5]
+        );
+        assert!(list.contains(&4));
+        assert!(!list.contains(&6));
+
+        assert_eq!(list.pop_min().as_deref(), Some(&1));
+        assert_eq!(list.pop_max().as_deref(), Some(&5));
+        assert_eq!(list.remove(&node).as_deref(), Some(&2));
+        assert_eq!(list.min().as_deref(), Some(&1));
+        assert_eq!(list.max().as_deref(), Some(&4));
+    }
+
+    #[test]
+    fn binary_search() {
+        let list = [1, 3, 5, 7, 9, 11].into_iter().collect::<SortedList<_>>();
+
+        let value = |node: SortedNode<i32>| *node.value();
+
+        assert_eq!(list.binary_search(&3).map(value), Ok(3));
+        assert_eq!(list.binary_search(&9).map(value), Ok(9));
+        assert_eq!(
+            list.binary_search(&0).map_err(|node| node.map(value)),
+            Err(Some(1))
+        );
+        assert_eq!(
+            list.binary_search(&6).map_err(|node| node.map(value)),
+            Err(Some(7))
+        );
+        assert_eq!(
+            list.binary_search(&10).map_err(|node| node.map(value)),
+            Err(Some(11))
+        );
+        assert_eq!(
+            list.binary_search(&12).map_err(|node| node.map(value)),
+            Err(None)
+        );
+        assert!(SortedList::new()
+            .binary_search(&1)
+            .is_err_and(|node| node.is_none()));
+
+        let node = list.binary_search_by(|probe| probe.cmp(&5)).unwrap();
+
+        assert_eq!(node.left().map(value), Some(3));
+        assert_eq!(list.head().map(value), Some(1));
+        assert_eq!(list.tail().map(value), Some(11));
+        assert_eq!(list.remove(&node).as_deref(), Some(&5));
+        assert!(node.is_insulate());
+    }
+
+    #[test]
+    fn push() {
+        let list = [2, 3].into_iter().collect::<SortedList<_>>();
+
+        assert_eq!(list.push_front(4).unwrap_err().into_inner(), 4);
+        assert_eq!(list.push_back(1).unwrap_err().into_inner(), 1);
+        assert!(list.push_front(2).is_ok());
+        assert!(list.push_back(5).is_ok());
+
+        assert_eq!(list.into_inner().into_vec(), vec![2, 2, 3, 5]);
+    }
+
+    #[test]
+    fn concurrent() {
+        let list = SortedList::new();
+
+        thread::scope(|s| {
+            for offset in 0..4 {
+                let list = &list;
+
+                s.spawn(move || {
+                    for value in 0..100 {
+                        list.insert_sorted(value * 4 + offset);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            list.iter().map(|a| *a).collect::<Vec<_>>(),
+            (0..400).collect::<Vec<_>>()
+        );
+    }
+}
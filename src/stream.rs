@@ -0,0 +1,62 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use alloc::sync::Arc;
+
+use futures::Stream;
+
+use crate::SharedList;
+
+pub struct IntoStream<T> {
+    list: SharedList<T>,
+}
+
+impl<T> SharedList<T> {
+    pub fn into_stream(self) -> IntoStream<T> {
+        IntoStream { list: self }
+    }
+}
+
+impl<T> Stream for IntoStream<T> {
+    type Item = Arc<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.list.poll_pop_front(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::SharedList;
+
+    #[test]
+    fn into_stream() {
+        let list = SharedList::from(vec![1, 2]);
+
+        let producer = thread::spawn({
+            let list = list.clone();
+
+            move || {
+                for value in 3..=5 {
+                    thread::sleep(Duration::from_millis(5));
+                    list.push_back(value).unwrap();
+                }
+
+                list.close();
+            }
+        });
+
+        let values = block_on(list.clone().into_stream().map(|a| *a).collect::<Vec<_>>());
+
+        producer.join().unwrap();
+
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,520 @@
+use core::cell::Cell;
+
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+#[cfg(loom)]
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+#[cfg(not(loom))]
+use core::hint;
+
+#[cfg(loom)]
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::Ordering::{Acquire, Relaxed, Release},
+};
+
+#[cfg(loom)]
+use loom::thread;
+
+#[cfg(all(not(loom), feature = "std"))]
+use std::thread;
+
+use backend::raw;
+
+#[cfg(all(not(loom), feature = "metrics"))]
+use crate::metrics::{self, Counter};
+
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicBool, AtomicUsize};
+
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{AtomicBool, AtomicUsize};
+
+#[cfg(feature = "std")]
+pub use std::sync::{LockResult, TryLockError, TryLockResult};
+
+#[cfg(feature = "std")]
+use std::sync::PoisonError;
+
+#[cfg(not(feature = "std"))]
+pub struct PoisonError<T> {
+    guard: T,
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> PoisonError<T> {
+    pub fn into_inner(self) -> T {
+        self.guard
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> fmt::Debug for PoisonError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoisonError").finish_non_exhaustive()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub enum TryLockError<T> {
+    Poisoned(PoisonError<T>),
+    WouldBlock,
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> fmt::Display for TryLockError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned(_) => f.write_str("poisoned lock: another task failed inside"),
+            Self::WouldBlock => f.write_str("try_lock failed because the operation would block"),
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub type LockResult<G> = Result<G, PoisonError<G>>;
+
+#[cfg(not(feature = "std"))]
+pub type TryLockResult<G> = Result<G, TryLockError<G>>;
+
+#[cfg(not(loom))]
+const SPIN_LIMIT: u32 = 6;
+#[cfg(all(not(loom), feature = "std"))]
+const YIELD_LIMIT: u32 = 10;
+#[cfg(all(not(loom), feature = "std"))]
+const PARK_LIMIT: u32 = 20;
+
+pub(crate) struct Backoff {
+    step: Cell<u32>,
+}
+
+impl Backoff {
+    pub(crate) fn new() -> Self {
+        Self { step: Cell::new(0) }
+    }
+
+    #[cfg(loom)]
+    pub(crate) fn snooze(&self) {
+        if self.step.replace(1) > 0 {
+            thread::yield_now();
+        }
+    }
+
+    #[cfg(all(not(loom), feature = "std"))]
+    pub(crate) fn snooze(&self) {
+        let step = self.step.get();
+
+        #[cfg(feature = "metrics")]
+        if step > 0 {
+            metrics::count(Counter::Retry);
+            metrics::count(if step > YIELD_LIMIT {
+                Counter::Park
+            } else if step > SPIN_LIMIT {
+                Counter::Yield
+            } else {
+                Counter::Spin
+            });
+        }
+
+        if step > YIELD_LIMIT {
+            thread::park_timeout(Duration::from_micros(1 << (step - YIELD_LIMIT)));
+        } else if step > SPIN_LIMIT {
+            thread::yield_now();
+        } else {
+            for _ in 1..1 << step {
+                hint::spin_loop();
+            }
+        }
+
+        self.step.set(PARK_LIMIT.min(step + 1));
+    }
+
+    #[cfg(all(not(loom), not(feature = "std")))]
+    pub(crate) fn snooze(&self) {
+        let step = self.step.get();
+
+        #[cfg(feature = "metrics")]
+        if step > 0 {
+            metrics::count(Counter::Retry);
+            metrics::count(Counter::Spin);
+        }
+
+        for _ in 1..1 << step {
+            hint::spin_loop();
+        }
+
+        self.step.set(SPIN_LIMIT.min(step + 1));
+    }
+}
+
+macro_rules! emit {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+macro_rules! omit {
+    ($($item:item)*) => {};
+}
+
+pub(crate) use {emit, omit};
+
+#[allow(unused_macros)]
+macro_rules! waiting_condvar {
+    () => {
+        pub(crate) struct Condvar {
+            inner: raw::Condvar,
+        }
+
+        impl Condvar {
+            pub(crate) fn new() -> Self {
+                Self {
+                    inner: raw::Condvar::new(),
+                }
+            }
+
+            pub(crate) fn notify_one(&self) {
+                self.inner.notify_one();
+            }
+
+            pub(crate) fn notify_all(&self) {
+                self.inner.notify_all();
+            }
+
+            pub(crate) fn wait<'a, T>(
+                &self,
+                guard: MutexGuard<'a, T>,
+            ) -> LockResult<MutexGuard<'a, T>> {
+                Ok(self
+                    .inner
+                    .wait(guard)
+                    .unwrap_or_else(PoisonError::into_inner))
+            }
+
+            pub(crate) fn wait_timeout<'a, T>(
+                &self,
+                guard: MutexGuard<'a, T>,
+                timeout: Duration,
+            ) -> LockResult<(MutexGuard<'a, T>, bool)> {
+                let (guard, result) = self
+                    .inner
+                    .wait_timeout(guard, timeout)
+                    .unwrap_or_else(PoisonError::into_inner);
+
+                Ok((guard, result.timed_out()))
+            }
+        }
+    };
+}
+
+// The lock backend is chosen here and nowhere else: loom under `--cfg loom`,
+// then `spin`, then `parking_lot`, then `std`. Only the spin backend lacks a
+// real condition variable, so it compiles out every blocking wait.
+#[cfg(loom)]
+mod backend {
+    use super::{Duration, LockResult, MutexGuard, PoisonError};
+
+    pub(crate) use super::{emit as blocking, omit as spinning};
+    pub(crate) use loom::sync as raw;
+
+    waiting_condvar!();
+}
+
+#[cfg(all(not(loom), feature = "spin"))]
+mod backend {
+    pub(crate) use super::{emit as spinning, omit as blocking};
+    pub(crate) use spin as raw;
+
+    pub(crate) struct Condvar;
+
+    impl Condvar {
+        pub(crate) fn new() -> Self {
+            Self
+        }
+
+        pub(crate) fn notify_one(&self) {}
+
+        pub(crate) fn notify_all(&self) {}
+    }
+}
+
+#[cfg(all(not(loom), not(feature = "spin"), feature = "parking_lot"))]
+mod backend {
+    use super::{Duration, LockResult, MutexGuard};
+
+    pub(crate) use super::{emit as blocking, omit as spinning};
+    pub(crate) use parking_lot as raw;
+
+    pub(crate) struct Condvar {
+        inner: raw::Condvar,
+    }
+
+    impl Condvar {
+        pub(crate) fn new() -> Self {
+            Self {
+                inner: raw::Condvar::new(),
+            }
+        }
+
+        pub(crate) fn notify_one(&self) {
+            self.inner.notify_one();
+        }
+
+        pub(crate) fn notify_all(&self) {
+            self.inner.notify_all();
+        }
+
+        pub(crate) fn wait<'a, T>(
+            &self,
+            mut guard: MutexGuard<'a, T>,
+        ) -> LockResult<MutexGuard<'a, T>> {
+            self.inner.wait(&mut guard);
+
+            Ok(guard)
+        }
+
+        pub(crate) fn wait_timeout<'a, T>(
+            &self,
+            mut guard: MutexGuard<'a, T>,
+            timeout: Duration,
+        ) -> LockResult<(MutexGuard<'a, T>, bool)> {
+            let result = self.inner.wait_for(&mut guard, timeout);
+
+            Ok((guard, result.timed_out()))
+        }
+    }
+}
+
+#[cfg(all(not(loom), not(feature = "spin"), not(feature = "parking_lot")))]
+mod backend {
+    use super::{Duration, LockResult, MutexGuard, PoisonError};
+
+    pub(crate) use super::{emit as blocking, omit as spinning};
+    pub(crate) use std::sync as raw;
+
+    waiting_condvar!();
+}
+
+pub(crate) use backend::{blocking, spinning, Condvar};
+
+// std and loom report poisoning and return a `Result` from `try_lock`, while
+// spin and parking_lot hand back the guard or an `Option`; these two traits
+// fold both shapes into the std signatures without per-backend methods.
+trait IntoGuard<G> {
+    fn into_guard(self) -> G;
+}
+
+impl<G> IntoGuard<G> for LockResult<G> {
+    fn into_guard(self) -> G {
+        self.unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<'a, T: ?Sized> IntoGuard<&'a mut T> for &'a mut T {
+    fn into_guard(self) -> Self {
+        self
+    }
+}
+
+impl<'a, T> IntoGuard<MutexGuard<'a, T>> for MutexGuard<'a, T> {
+    fn into_guard(self) -> Self {
+        self
+    }
+}
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+impl<'a, T> IntoGuard<RwLockReadGuard<'a, T>> for RwLockReadGuard<'a, T> {
+    fn into_guard(self) -> Self {
+        self
+    }
+}
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+impl<'a, T> IntoGuard<RwLockWriteGuard<'a, T>> for RwLockWriteGuard<'a, T> {
+    fn into_guard(self) -> Self {
+        self
+    }
+}
+
+trait IntoTryGuard<G> {
+    fn into_try_guard(self) -> TryLockResult<G>;
+}
+
+impl<G> IntoTryGuard<G> for TryLockResult<G> {
+    fn into_try_guard(self) -> TryLockResult<G> {
+        match self {
+            Err(TryLockError::Poisoned(err)) => Ok(err.into_inner()),
+            result => result,
+        }
+    }
+}
+
+impl<G> IntoTryGuard<G> for Option<G> {
+    fn into_try_guard(self) -> TryLockResult<G> {
+        self.ok_or(TryLockError::WouldBlock)
+    }
+}
+
+pub(crate) type MutexGuard<'a, T> = raw::MutexGuard<'a, T>;
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+pub(crate) type RwLockReadGuard<'a, T> = raw::RwLockReadGuard<'a, T>;
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+pub(crate) type RwLockWriteGuard<'a, T> = raw::RwLockWriteGuard<'a, T>;
+
+#[cfg(all(not(loom), not(feature = "rwlock")))]
+pub(crate) type NodeLock<T> = Mutex<T>;
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+pub(crate) type NodeLock<T> = RwLock<T>;
+
+#[cfg(loom)]
+pub(crate) type NodeLock<T> = SpinLock<T>;
+
+pub(crate) struct Mutex<T> {
+    inner: raw::Mutex<T>,
+}
+
+impl<T> Mutex<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            inner: raw::Mutex::new(value),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+        Ok(self.inner.lock().into_guard())
+    }
+
+    #[cfg(all(not(loom), not(feature = "rwlock")))]
+    pub(crate) fn read(&self) -> LockResult<MutexGuard<'_, T>> {
+        self.lock()
+    }
+
+    pub(crate) fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
+        self.inner.try_lock().into_try_guard()
+    }
+
+    pub(crate) fn get_mut(&mut self) -> LockResult<&mut T> {
+        Ok(self.inner.get_mut().into_guard())
+    }
+}
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+pub(crate) struct RwLock<T> {
+    inner: raw::RwLock<T>,
+}
+
+#[cfg(all(not(loom), feature = "rwlock"))]
+impl<T> RwLock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            inner: raw::RwLock::new(value),
+        }
+    }
+
+    pub(crate) fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        Ok(self.inner.read().into_guard())
+    }
+
+    pub(crate) fn lock(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        Ok(self.inner.write().into_guard())
+    }
+
+    pub(crate) fn try_lock(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        self.inner.try_write().into_try_guard()
+    }
+}
+
+#[cfg(loom)]
+pub(crate) struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+#[cfg(loom)]
+unsafe impl<T: Send> Send for SpinLock<T> {}
+
+#[cfg(loom)]
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+#[cfg(loom)]
+impl<T> SpinLock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> LockResult<SpinLockGuard<'_, T>> {
+        loop {
+            if let Ok(guard) = self.try_lock() {
+                break Ok(guard);
+            }
+
+            thread::yield_now();
+        }
+    }
+
+    pub(crate) fn read(&self) -> LockResult<SpinLockGuard<'_, T>> {
+        self.lock()
+    }
+
+    pub(crate) fn try_lock(&self) -> TryLockResult<SpinLockGuard<'_, T>> {
+        match self.locked.compare_exchange(false, true, Acquire, Relaxed) {
+            Ok(_) => Ok(SpinLockGuard { lock: self }),
+            Err(_) => Err(TryLockError::WouldBlock),
+        }
+    }
+}
+
+#[cfg(loom)]
+pub(crate) struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+#[cfg(loom)]
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+#[cfg(loom)]
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+#[cfg(loom)]
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Release);
+    }
+}
+
+#[cfg(all(test, not(loom), feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let backoff = Backoff::new();
+
+        for _ in 0..PARK_LIMIT * 2 {
+            backoff.snooze();
+        }
+
+        assert_eq!(backoff.step.get(), PARK_LIMIT);
+    }
+}
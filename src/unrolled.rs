@@ -0,0 +1,599 @@
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+
+use alloc::boxed::Box;
+
+use crate::allocator::{self, Allocator, Global};
+
+struct Chunk<T, const N: usize> {
+    left: Option<NonNull<Chunk<T, N>>>,
+    right: Option<NonNull<Chunk<T, N>>>,
+    start: usize,
+    end: usize,
+    values: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    fn alloc<A: Allocator>(
+        alloc: &A,
+        left: Option<NonNull<Self>>,
+        right: Option<NonNull<Self>>,
+        start: usize,
+    ) -> NonNull<Self> {
+        allocator::allocate(
+            alloc,
+            Self {
+                left,
+                right,
+                start,
+                end: start,
+                values: [const { MaybeUninit::uninit() }; N],
+            },
+        )
+    }
+}
+
+pub struct UnrolledList<T, const N: usize, A: Allocator = Global> {
+    head: Option<NonNull<Chunk<T, N>>>,
+    tail: Option<NonNull<Chunk<T, N>>>,
+    len: usize,
+    alloc: A,
+    _chunks: PhantomData<Box<Chunk<T, N>>>,
+}
+
+unsafe impl<T: Send, const N: usize, A: Allocator + Send> Send for UnrolledList<T, N, A> {}
+unsafe impl<T: Sync, const N: usize, A: Allocator + Sync> Sync for UnrolledList<T, N, A> {}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "chunk size must be non-zero") };
+
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc: Global,
+            _chunks: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize, A: Allocator> UnrolledList<T, N, A> {
+    #[cfg(feature = "allocator-api2")]
+    pub const fn new_in(alloc: A) -> Self {
+        const { assert!(N > 0, "chunk size must be non-zero") };
+
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            _chunks: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|chunk| unsafe {
+            let chunk = &*chunk.as_ptr();
+
+            chunk.values[chunk.start].assume_init_ref()
+        })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|chunk| unsafe {
+            let chunk = &*chunk.as_ptr();
+
+            chunk.values[chunk.end - 1].assume_init_ref()
+        })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|chunk| unsafe {
+            let chunk = &mut *chunk.as_ptr();
+
+            chunk.values[chunk.start].assume_init_mut()
+        })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|chunk| unsafe {
+            let chunk = &mut *chunk.as_ptr();
+
+            chunk.values[chunk.end - 1].assume_init_mut()
+        })
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let head = match self.head {
+            Some(head) if unsafe { (*head.as_ptr()).start } > 0 => head,
+            _ => {
+                let chunk = Chunk::alloc(&self.alloc, None, self.head, N);
+
+                match self.head {
+                    Some(head) => unsafe { (*head.as_ptr()).left = chunk.into() },
+                    None => self.tail = chunk.into(),
+                }
+
+                self.head = chunk.into();
+
+                chunk
+            }
+        };
+
+        let head = unsafe { &mut *head.as_ptr() };
+
+        head.start -= 1;
+        head.values[head.start].write(value);
+
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let tail = match self.tail {
+            Some(tail) if unsafe { (*tail.as_ptr()).end } < N => tail,
+            _ => {
+                let chunk = Chunk::alloc(&self.alloc, self.tail, None, 0);
+
+                match self.tail {
+                    Some(tail) => unsafe { (*tail.as_ptr()).right = chunk.into() },
+                    None => self.head = chunk.into(),
+                }
+
+                self.tail = chunk.into();
+
+                chunk
+            }
+        };
+
+        let tail = unsafe { &mut *tail.as_ptr() };
+
+        tail.values[tail.end].write(value);
+        tail.end += 1;
+
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            let chunk = unsafe { &mut *head.as_ptr() };
+            let value = unsafe { chunk.values[chunk.start].assume_init_read() };
+
+            chunk.start += 1;
+
+            if chunk.start == chunk.end {
+                let chunk = unsafe { allocator::deallocate(&self.alloc, head) };
+
+                self.head = chunk.right;
+
+                match self.head {
+                    Some(right) => unsafe { (*right.as_ptr()).left = None },
+                    None => self.tail = None,
+                }
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| {
+            let chunk = unsafe { &mut *tail.as_ptr() };
+
+            chunk.end -= 1;
+
+            let value = unsafe { chunk.values[chunk.end].assume_init_read() };
+
+            if chunk.start == chunk.end {
+                let chunk = unsafe { allocator::deallocate(&self.alloc, tail) };
+
+                self.tail = chunk.left;
+
+                match self.tail {
+                    Some(left) => unsafe { (*left.as_ptr()).right = None },
+                    None => self.head = None,
+                }
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn iter(&self) -> UnrolledIter<'_, T, N> {
+        UnrolledIter {
+            front: self.head,
+            front_index: self
+                .head
+                .map_or(0, |chunk| unsafe { (*chunk.as_ptr()).start }),
+            back: self.tail,
+            back_index: self
+                .tail
+                .map_or(0, |chunk| unsafe { (*chunk.as_ptr()).end }),
+            len: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> UnrolledIterMut<'_, T, N> {
+        UnrolledIterMut {
+            front: self.head,
+            front_index: self
+                .head
+                .map_or(0, |chunk| unsafe { (*chunk.as_ptr()).start }),
+            back: self.tail,
+            back_index: self
+                .tail
+                .map_or(0, |chunk| unsafe { (*chunk.as_ptr()).end }),
+            len: self.len,
+            _list: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize, A: Allocator> Drop for UnrolledList<T, N, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledList<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, A: Allocator> fmt::Debug for UnrolledList<T, N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize, A: Allocator> PartialEq for UnrolledList<T, N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize, A: Allocator> Eq for UnrolledList<T, N, A> {}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T, const N: usize, A: Allocator> Extend<T> for UnrolledList<T, N, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+pub struct UnrolledIter<'a, T, const N: usize> {
+    front: Option<NonNull<Chunk<T, N>>>,
+    front_index: usize,
+    back: Option<NonNull<Chunk<T, N>>>,
+    back_index: usize,
+    len: usize,
+    _list: PhantomData<&'a Chunk<T, N>>,
+}
+
+impl<'a, T, const N: usize> Iterator for UnrolledIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.map(|chunk| {
+            let chunk = unsafe { &*chunk.as_ptr() };
+            let value = unsafe { chunk.values[self.front_index].assume_init_ref() };
+
+            self.front_index += 1;
+
+            if self.front_index == chunk.end {
+                self.front = chunk.right;
+                self.front_index = chunk
+                    .right
+                    .map_or(0, |right| unsafe { (*right.as_ptr()).start });
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for UnrolledIter<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.map(|chunk| {
+            let chunk = unsafe { &*chunk.as_ptr() };
+
+            self.back_index -= 1;
+
+            let value = unsafe { chunk.values[self.back_index].assume_init_ref() };
+
+            if self.back_index == chunk.start {
+                self.back = chunk.left;
+                self.back_index = chunk.left.map_or(0, |left| unsafe { (*left.as_ptr()).end });
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for UnrolledIter<'_, T, N> {}
+
+pub struct UnrolledIterMut<'a, T, const N: usize> {
+    front: Option<NonNull<Chunk<T, N>>>,
+    front_index: usize,
+    back: Option<NonNull<Chunk<T, N>>>,
+    back_index: usize,
+    len: usize,
+    _list: PhantomData<&'a mut Chunk<T, N>>,
+}
+
+impl<'a, T, const N: usize> Iterator for UnrolledIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.front.map(|chunk| {
+            let chunk = unsafe { &mut *chunk.as_ptr() };
+            let value = unsafe { &mut *chunk.values[self.front_index].as_mut_ptr() };
+
+            self.front_index += 1;
+
+            if self.front_index == chunk.end {
+                self.front = chunk.right;
+                self.front_index = chunk
+                    .right
+                    .map_or(0, |right| unsafe { (*right.as_ptr()).start });
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for UnrolledIterMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.back.map(|chunk| {
+            let chunk = unsafe { &mut *chunk.as_ptr() };
+
+            self.back_index -= 1;
+
+            let value = unsafe { &mut *chunk.values[self.back_index].as_mut_ptr() };
+
+            if self.back_index == chunk.start {
+                self.back = chunk.left;
+                self.back_index = chunk.left.map_or(0, |left| unsafe { (*left.as_ptr()).end });
+            }
+
+            self.len -= 1;
+
+            value
+        })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for UnrolledIterMut<'_, T, N> {}
+
+pub struct UnrolledIntoIter<T, const N: usize, A: Allocator = Global> {
+    list: UnrolledList<T, N, A>,
+}
+
+impl<T, const N: usize, A: Allocator> Iterator for UnrolledIntoIter<T, N, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, const N: usize, A: Allocator> DoubleEndedIterator for UnrolledIntoIter<T, N, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, const N: usize, A: Allocator> ExactSizeIterator for UnrolledIntoIter<T, N, A> {}
+
+impl<T, const N: usize, A: Allocator> IntoIterator for UnrolledList<T, N, A> {
+    type Item = T;
+    type IntoIter = UnrolledIntoIter<T, N, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UnrolledIntoIter { list: self }
+    }
+}
+
+impl<'a, T, const N: usize, A: Allocator> IntoIterator for &'a UnrolledList<T, N, A> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize, A: Allocator> IntoIterator for &'a mut UnrolledList<T, N, A> {
+    type Item = &'a mut T;
+    type IntoIter = UnrolledIterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut list = UnrolledList::<_, 2>::new();
+
+        list.push_back(3);
+        list.push_front(2);
+        list.push_back(4);
+        list.push_front(1);
+        list.push_back(5);
+        list.push_front(0);
+
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&5));
+
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = (1..=7).collect::<UnrolledList<_, 3>>();
+
+        list.push_front(0);
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![7, 6, 5, 4, 3, 2, 1, 0]
+        );
+
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        *list.back_mut().unwrap() += 1;
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&71));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.by_ref().rev().nth(5), Some(&10));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.clone(), list);
+        assert_eq!(
+            list.into_iter().rev().collect::<Vec<_>>(),
+            vec![71, 60, 50, 40, 30, 20, 10, 0]
+        );
+    }
+
+    #[test]
+    fn drop() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = (0..100)
+            .map(|_| Rc::clone(&value))
+            .collect::<UnrolledList<_, 8>>();
+
+        list.pop_front();
+        list.pop_back();
+
+        assert_eq!(Rc::strong_count(&value), 99);
+
+        std::mem::drop(list);
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn new_in() {
+        use core::cell::Cell;
+
+        use crate::allocator::Counting;
+
+        let live = Cell::new(0);
+
+        {
+            let mut list = UnrolledList::<_, 2, _>::new_in(Counting(&live));
+
+            list.extend([1, 2, 3]);
+
+            assert_eq!(live.get(), 2);
+            assert_eq!(list.pop_back(), Some(3));
+            assert_eq!(live.get(), 1);
+
+            list.push_front(0);
+
+            assert_eq!(live.get(), 2);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        }
+
+        assert_eq!(live.get(), 0);
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt;
+
+use alloc::sync::Arc;
+
+use crate::SharedList;
+
+pub struct WorkStealingDeque<T> {
+    list: SharedList<T>,
+}
+
+impl<T> WorkStealingDeque<T> {
+    pub fn new() -> Self {
+        Self {
+            list: SharedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn push(&self, value: T) {
+        let _ = self.list.push_back(value);
+    }
+
+    pub fn pop(&self) -> Option<Arc<T>> {
+        self.list.pop_back()
+    }
+
+    pub fn steal(&self) -> Option<Arc<T>> {
+        self.list.steal_front()
+    }
+}
+
+impl<T> Default for WorkStealingDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for WorkStealingDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T> FromIterator<T> for WorkStealingDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            list: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, thread};
+
+    use super::*;
+
+    #[test]
+    fn push_pop_steal() {
+        let deque = (1..=4).collect::<WorkStealingDeque<_>>();
+
+        assert_eq!(deque.pop().as_deref(), Some(&4));
+        assert_eq!(deque.steal().as_deref(), Some(&1));
+        assert_eq!(deque.steal().as_deref(), Some(&2));
+        assert_eq!(deque.steal().as_deref(), Some(&3));
+        assert_eq!(deque.steal(), None);
+        assert_eq!(deque.pop(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn concurrent() {
+        let deque = WorkStealingDeque::new();
+        let seen = Mutex::new(Vec::new());
+
+        thread::scope(|s| {
+            for _ in 0..3 {
+                s.spawn(|| {
+                    let mut stolen = Vec::new();
+
+                    for _ in 0..2000 {
+                        if let Some(value) = deque.steal() {
+                            stolen.push(*value);
+                        }
+                    }
+
+                    seen.lock().unwrap().extend(stolen);
+                });
+            }
+
+            let mut popped = Vec::new();
+
+            for value in 0..2000 {
+                deque.push(value);
+
+                if value % 3 == 0 {
+                    popped.extend(deque.pop().map(|a| *a));
+                }
+            }
+
+            seen.lock().unwrap().extend(popped);
+        });
+
+        let mut seen = seen.into_inner().unwrap();
+
+        seen.extend(deque.list.drain().map(|a| *a));
+        seen.sort();
+
+        assert_eq!(seen, (0..2000).collect::<Vec<_>>());
+        assert!(deque.is_empty());
+    }
+}